@@ -7,7 +7,7 @@ use super::args::EnvArgs;
 use super::config::listener_config::ListenerConfig;
 use super::config::Config;
 use super::logger::init_logger;
-use super::server::Server;
+use super::server::{routing::rule_set::rule::respond::shared_state::RespondSharedState, Server};
 use app_state::AppState;
 
 /// app
@@ -39,7 +39,10 @@ impl App {
             config.listener = Some(listener);
         }
 
-        let app_state = AppState { config };
+        let app_state = AppState {
+            config,
+            respond_shared_state: RespondSharedState::default(),
+        };
 
         let server = Server::new(app_state).await;
 
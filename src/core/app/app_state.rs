@@ -1,6 +1,9 @@
 use crate::core::config::Config;
+use crate::core::server::routing::rule_set::rule::respond::shared_state::RespondSharedState;
 
 #[derive(Clone)]
 pub struct AppState {
     pub config: Config,
+    /// shared among clones so that sequence and one_of responds proceed across requests
+    pub respond_shared_state: RespondSharedState,
 }
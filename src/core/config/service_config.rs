@@ -1,3 +1,4 @@
+use cache_config::CacheConfig;
 use console::style;
 use serde::Deserialize;
 use strategy::Strategy;
@@ -5,6 +6,7 @@ use util::canonicalized_fallback_respond_dir_to_print;
 
 use std::path::Path;
 
+pub mod cache_config;
 pub mod strategy;
 mod util;
 
@@ -13,7 +15,7 @@ use crate::core::server::{
     middleware::Middleware,
     parsed_request::ParsedRequest,
     response::{error_response::internal_server_error_response, file_response::FileResponse},
-    routing::rule_set::RuleSet,
+    routing::rule_set::{rule::respond::shared_state::RespondSharedState, RuleSet},
     types::BoxBody,
 };
 
@@ -33,6 +35,15 @@ pub struct ServiceConfig {
     pub middlewares: Vec<Middleware>,
 
     pub fallback_respond_dir: String,
+
+    /// send response bodies at the rate instead of at once unless rule sets its own
+    pub throttle_bytes_per_second: Option<u64>,
+
+    /// cache-control policy and conditional requests on file responses
+    pub cache: Option<CacheConfig>,
+
+    /// compress response bodies by accept-encoding unless rule sets its own
+    pub compression: Option<bool>,
 }
 
 impl ServiceConfig {
@@ -101,12 +112,16 @@ impl ServiceConfig {
     pub async fn rule_set_response(
         &self,
         parsed_request: &ParsedRequest,
+        respond_shared_state: &RespondSharedState,
     ) -> Option<Result<hyper::Response<BoxBody>, hyper::http::Error>> {
         for (rule_set_idx, rule_set) in self.rule_sets.iter().enumerate() {
             match rule_set.find_matched(parsed_request, self.strategy.as_ref(), rule_set_idx) {
-                Some(respond) => {
+                Some((rule_idx, respond, captures)) => {
                     let dir_prefix = rule_set.dir_prefix();
-                    let response = respond.response(dir_prefix.as_str(), &parsed_request).await;
+                    let response = respond
+                        .resolve(respond_shared_state, (rule_set_idx, rule_idx))
+                        .response(dir_prefix.as_str(), &parsed_request, &captures)
+                        .await;
                     return Some(response);
                 }
                 None => (),
@@ -156,7 +171,14 @@ impl ServiceConfig {
             );
         }
 
-        rule_sets_validate && fallback_respond_dir_validate
+        let throttle_validate = self.throttle_bytes_per_second != Some(0);
+        if !throttle_validate {
+            log::error!("throttle_bytes_per_second must be positive");
+        }
+
+        let cache_validate = self.cache.is_none() || self.cache.as_ref().unwrap().validate();
+
+        rule_sets_validate && fallback_respond_dir_validate && throttle_validate && cache_validate
     }
 }
 
@@ -169,6 +191,9 @@ impl Default for ServiceConfig {
             middlewares_file_paths: None,
             middlewares: vec![],
             fallback_respond_dir: SERVICE_DEFAULT_FALLBACK_RESPOND_DIR.to_owned(),
+            throttle_bytes_per_second: None,
+            cache: None,
+            compression: None,
         }
     }
 }
@@ -205,6 +230,19 @@ impl std::fmt::Display for ServiceConfig {
             "[fallback_respond_dir] {}",
             canonicalized_fallback_respond_dir_to_print(self.fallback_respond_dir.as_str())
         );
+        if let Some(bytes_per_second) = self.throttle_bytes_per_second {
+            let _ = writeln!(f, "[throttle_bytes_per_second] {}", bytes_per_second);
+        }
+        if let Some(compression) = self.compression {
+            let _ = writeln!(
+                f,
+                "[compression] {}",
+                if compression { "Yes" } else { "No" }
+            );
+        }
+        if let Some(cache) = self.cache.as_ref() {
+            let _ = write!(f, "{}", cache);
+        }
 
         Ok(())
     }
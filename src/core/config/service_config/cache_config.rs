@@ -0,0 +1,42 @@
+use hyper::header::HeaderValue;
+use serde::Deserialize;
+
+/// cache validators and cache-control policy of responses
+#[derive(Clone, Default, Deserialize)]
+pub struct CacheConfig {
+    /// `cache-control` response header value instead of the default `no-store`
+    pub cache_control: Option<String>,
+    /// `etag` of file responses from content hash
+    #[serde(default)]
+    pub etag: bool,
+    /// `last-modified` of file responses from file modified time
+    #[serde(default)]
+    pub last_modified: bool,
+}
+
+impl CacheConfig {
+    /// validate
+    pub fn validate(&self) -> bool {
+        match self.cache_control.as_ref() {
+            Some(cache_control) if HeaderValue::from_str(cache_control.as_str()).is_err() => {
+                log::error!("cache_control is invalid header value: {}", cache_control);
+                false
+            }
+            _ => true,
+        }
+    }
+}
+
+impl std::fmt::Display for CacheConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let _ = writeln!(
+            f,
+            "[service.cache] cache_control = {}, etag = {}, last_modified = {}",
+            self.cache_control.as_deref().unwrap_or("(default)"),
+            if self.etag { "Yes" } else { "No" },
+            if self.last_modified { "Yes" } else { "No" }
+        );
+
+        Ok(())
+    }
+}
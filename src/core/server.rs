@@ -4,7 +4,7 @@ use hyper::{
     body,
     header::{HeaderValue, CONTENT_LENGTH},
     service::service_fn,
-    HeaderMap, Response,
+    HeaderMap, Response, StatusCode,
 };
 use hyper_util::{
     rt::{TokioExecutor, TokioIo},
@@ -17,6 +17,7 @@ use tokio::sync::Mutex;
 use std::net::{SocketAddr, ToSocketAddrs};
 use std::sync::Arc;
 
+mod connection;
 pub mod constant;
 pub mod middleware;
 pub mod parsed_request;
@@ -28,8 +29,14 @@ pub mod types;
 
 use crate::core::app::app_state::AppState;
 use crate::core::app::constant::APP_NAME;
+use connection::{ConnectionFault, FaultableStream};
+use constant::SEQUENCE_RESET_URL_PATH;
 use parsed_request::ParsedRequest;
-use response::error_response::internal_server_error_response;
+use response::{
+    cache_response::cache_response, compressed_response::compressed_response,
+    error_response::internal_server_error_response, fault_response::is_fault_error,
+    status_code_response::status_code_response, throttled_response::throttled_response,
+};
 use routing::dyn_route::dyn_route_content;
 use types::BoxBody;
 
@@ -65,24 +72,36 @@ impl Server {
 
         let app_state = Arc::new(Mutex::new(self.app_state.clone()));
         loop {
-            let (stream, _) = listener
+            let (stream, client_addr) = listener
                 .accept()
                 .await
                 .expect("tcp listener failed to accept");
-            let io = TokioIo::new(stream);
+            let connection_fault = ConnectionFault::default();
+            let io = TokioIo::new(FaultableStream::new(stream, connection_fault.clone()));
 
             let app_state = app_state.clone();
             tokio::task::spawn(async move {
                 if let Err(err) = Builder::new(TokioExecutor::new())
-                    .serve_connection(
+                    .serve_connection_with_upgrades(
                         io,
                         service_fn(move |request: hyper::Request<body::Incoming>| {
-                            service(request, app_state.clone())
+                            let app_state = app_state.clone();
+                            let connection_fault = connection_fault.clone();
+                            async move {
+                                match service(request, app_state, client_addr).await {
+                                    Ok(response) => connection_fault.handle(response),
+                                    Err(err) => Err(std::io::Error::other(err)),
+                                }
+                            }
                         }),
                     )
                     .await
                 {
-                    log::error!("error serving connection: {:?}", err);
+                    if is_fault_error(err.as_ref()) {
+                        log::info!("connection aborted on purpose: {}", err);
+                    } else {
+                        log::error!("error serving connection: {:?}", err);
+                    }
                 }
             });
         }
@@ -93,6 +112,7 @@ impl Server {
 pub async fn service(
     request: hyper::Request<body::Incoming>,
     app_state: Arc<Mutex<AppState>>,
+    client_addr: SocketAddr,
 ) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
     let request_headers = request.headers().clone();
 
@@ -101,7 +121,7 @@ pub async fn service(
         _ => (),
     };
 
-    let parsed_request = match ParsedRequest::from(request).await {
+    let parsed_request = match ParsedRequest::from(request, client_addr).await {
         Ok(x) => x,
         Err(err) => return internal_server_error_response(err.as_str(), &request_headers),
     };
@@ -113,29 +133,73 @@ pub async fn service(
 
     parsed_request.capture_in_log(config.log.unwrap_or_default().verbose);
 
-    match config.service.middleware_response(&parsed_request).await {
-        Some(x) => return x,
-        None => (),
+    if parsed_request.url_path == SEQUENCE_RESET_URL_PATH
+        && parsed_request.component_parts.method == hyper::Method::POST
+    {
+        shared_app_state
+            .respond_shared_state
+            .sequence_counters
+            .reset();
+        log::info!("sequence responds are reset");
+        return status_code_response(&StatusCode::NO_CONTENT, &request_headers);
     }
 
-    match config.service.rule_set_response(&parsed_request).await {
-        Some(x) => return x,
-        None => (),
+    let response = match config.service.middleware_response(&parsed_request).await {
+        Some(x) => x,
+        None => match config
+            .service
+            .rule_set_response(&parsed_request, &shared_app_state.respond_shared_state)
+            .await
+        {
+            Some(x) => x,
+            None => {
+                dyn_route_content(
+                    parsed_request.url_path.as_str(),
+                    config.service.fallback_respond_dir.as_str(),
+                    &request_headers,
+                )
+                .await
+            }
+        },
+    };
+
+    // websocket handshake is kept as it is because connection is upgraded
+    if response
+        .as_ref()
+        .is_ok_and(|x| x.status() == StatusCode::SWITCHING_PROTOCOLS)
+    {
+        return response;
     }
 
-    dyn_route_content(
-        parsed_request.url_path.as_str(),
-        config.service.fallback_respond_dir.as_str(),
-        &request_headers,
-    )
-    .await
+    let response = match (config.service.cache.as_ref(), response) {
+        (Some(cache_config), Ok(response)) => Ok(cache_response(
+            response,
+            cache_config,
+            &parsed_request.component_parts.method,
+            &request_headers,
+        )
+        .await),
+        (_, response) => response,
+    };
+
+    // service-wide compression unless rule sets its own
+    let response = match (config.service.compression, response) {
+        (Some(true), Ok(response)) => Ok(compressed_response(response, &request_headers).await),
+        (_, response) => response,
+    };
+
+    // service-wide throttle unless rule sets its own
+    match config.service.throttle_bytes_per_second {
+        Some(bytes_per_second) => response.map(|x| throttled_response(x, bytes_per_second)),
+        None => response,
+    }
 }
 
 /// OPTIONS request handler
 fn handle_options(
     request_headers: &HeaderMap,
 ) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
-    let mut response = Response::new(Empty::new().boxed());
+    let mut response = Response::new(Empty::new().map_err(|never| match never {}).boxed());
 
     // empty
     *response.status_mut() = hyper::StatusCode::NO_CONTENT;
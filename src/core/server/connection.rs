@@ -0,0 +1,108 @@
+use hyper::Response;
+use tokio::{
+    io::{AsyncRead, AsyncWrite, ReadBuf},
+    net::TcpStream,
+};
+
+use std::{
+    io,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+
+use super::{
+    response::fault_response::{Fault, FaultError},
+    types::BoxBody,
+};
+
+/// connection-level handling of faults in responses
+///
+/// shared between service and stream of each connection
+#[derive(Clone, Debug, Default)]
+pub struct ConnectionFault {
+    reset: Arc<AtomicBool>,
+}
+
+impl ConnectionFault {
+    /// pass response through, or turn fault in its extensions into connection behavior
+    ///
+    /// `close` fails service so that connection is closed without response.
+    /// `reset` makes stream reset instead of gracefully closed
+    pub fn handle(&self, response: Response<BoxBody>) -> io::Result<Response<BoxBody>> {
+        match response.extensions().get::<Fault>() {
+            Some(Fault::Close) => Err(io::Error::other(FaultError(Fault::Close))),
+            Some(Fault::Reset) => {
+                self.reset.store(true, Ordering::Relaxed);
+                Ok(response)
+            }
+            _ => Ok(response),
+        }
+    }
+}
+
+/// tcp stream which can be reset on fault
+pub struct FaultableStream {
+    stream: TcpStream,
+    fault: ConnectionFault,
+}
+
+impl FaultableStream {
+    pub fn new(stream: TcpStream, fault: ConnectionFault) -> Self {
+        Self { stream, fault }
+    }
+}
+
+impl Drop for FaultableStream {
+    fn drop(&mut self) {
+        if self.fault.reset.load(Ordering::Relaxed) {
+            // zero linger sends rst on close
+            if let Err(err) = self.stream.set_zero_linger() {
+                log::warn!("failed to reset connection: {}", err);
+            }
+        }
+    }
+}
+
+impl AsyncRead for FaultableStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().stream).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for FaultableStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().stream).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().stream).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().stream).poll_shutdown(cx)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().stream).poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.stream.is_write_vectored()
+    }
+}
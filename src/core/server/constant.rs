@@ -1,5 +1,12 @@
 pub const CSV_RECORDS_DEFAULT_KEY: &str = "records";
 
+/// buffer size to stream file body
+pub const FILE_STREAM_CHUNK_BYTES: usize = 64 * 1024;
+/// head of file checked if it is text
+pub const TEXT_FILE_SNIFF_BYTES: usize = 8 * 1024;
+/// file larger than it is streamed as binary even if it looks text
+pub const TEXT_FILE_MAX_BYTES: u64 = 16 * 1024 * 1024;
+
 const DEFAULT_ALLOWED_METHODS: &str = "GET, POST, PUT, DELETE, OPTIONS";
 pub const DEFAULT_RESPONSE_HEADERS: &[(&str, &str)] = &[
     ("access-control-allow-headers", "*"),
@@ -11,3 +18,6 @@ pub const DEFAULT_RESPONSE_HEADERS: &[(&str, &str)] = &[
 ];
 
 pub const ROOT_DIRECTORY_FILE_NAME: &str = "index";
+
+/// reserved url path to reset counters of sequence responds
+pub const SEQUENCE_RESET_URL_PATH: &str = "/__apimock/sequence/reset";
@@ -2,25 +2,53 @@ use console::style;
 use http_body_util::BodyExt;
 use hyper::header::ORIGIN;
 use hyper::http::request::Parts;
-use hyper::{body::Incoming, Version};
+use hyper::{
+    body::{Bytes, Incoming},
+    Version,
+};
 use serde_json::{to_string_pretty, Value};
 
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::{
+    net::SocketAddr,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use crate::core::{
     config::log_config::verbose_config::VerboseConfig,
-    util::http::{content_type_is_application_json, normalize_url_path},
+    util::{
+        http::{
+            content_type_is_application_json, content_type_mime, multipart_boundary,
+            normalize_url_path, parse_cookies, parse_query, request_host,
+        },
+        multipart::{parse_multipart, MultipartPart},
+    },
 };
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ParsedRequest {
     pub url_path: String,
+    /// percent-decoded url query key-value pairs (repeated keys are kept)
+    pub query_params: Vec<(String, String)>,
     pub component_parts: Parts,
+    /// peer address of connection
+    pub client_addr: SocketAddr,
+    /// cookie name-value pairs from cookie headers
+    pub cookies: Vec<(String, String)>,
+    /// host name in lowercase without port
+    pub host: Option<String>,
+    /// raw request body
+    pub body_bytes: Option<Bytes>,
     pub body_json: Option<Value>,
+    /// percent-decoded application/x-www-form-urlencoded key-value pairs
+    pub body_form: Option<Vec<(String, String)>>,
+    pub body_multipart: Option<Vec<MultipartPart>>,
 }
 
 impl ParsedRequest {
-    pub async fn from(request: hyper::Request<Incoming>) -> Result<Self, String> {
+    pub async fn from(
+        request: hyper::Request<Incoming>,
+        client_addr: SocketAddr,
+    ) -> Result<Self, String> {
         let (component_parts, body) = request.into_parts();
 
         let body_bytes = match body.boxed().collect().await {
@@ -31,42 +59,77 @@ impl ParsedRequest {
             }
         };
 
-        let has_body = body_bytes.is_some() && !body_bytes.as_ref().unwrap().is_empty();
-
         let mut body_json: Option<Value> = None;
-        if has_body {
-            let raw_body_json = serde_json::from_slice::<Option<Value>>(&body_bytes.unwrap());
+        let mut body_form: Option<Vec<(String, String)>> = None;
+        let mut body_multipart: Option<Vec<MultipartPart>> = None;
+        if let Some(body) = body_bytes.as_ref().filter(|x| !x.is_empty()) {
+            let raw_body_json = serde_json::from_slice::<Option<Value>>(body);
 
-            let _ = match content_type_is_application_json(&component_parts.headers) {
-                // case application/json: get json body
-                Some(x) if x && raw_body_json.is_err() => {
+            // case application/json: get json body
+            if content_type_is_application_json(&component_parts.headers) == Some(true) {
+                if let Err(err) = raw_body_json.as_ref() {
                     return Err(format!(
                         "failed to get json value from request body: {}",
-                        raw_body_json.unwrap_err()
+                        err
                     ));
                 }
-                // todo: support other types than json (such as form value) in the future
-                Some(x) if !x => {
-                    log::warn!("request has body but its content-type is not application/json")
+            }
+
+            match content_type_mime(&component_parts.headers).as_deref() {
+                Some("application/x-www-form-urlencoded") => {
+                    body_form = Some(parse_query(String::from_utf8_lossy(body).as_ref()))
                 }
+                Some("multipart/form-data") => match multipart_boundary(&component_parts.headers) {
+                    Some(boundary) => {
+                        body_multipart = Some(parse_multipart(body, boundary.as_str()))
+                    }
+                    None => log::warn!("request has multipart body but doesn't have boundary"),
+                },
+                Some(_) => (),
                 None => log::warn!("request has body but doesn't have content-type"),
-                _ => (),
-            };
+            }
 
-            if raw_body_json.is_ok() {
-                body_json = raw_body_json.unwrap();
+            if let Ok(x) = raw_body_json {
+                body_json = x;
             }
         }
 
         let url_path = normalize_url_path(component_parts.uri.path(), None);
 
+        let query_params = match component_parts.uri.query() {
+            Some(query) => parse_query(query),
+            None => vec![],
+        };
+
+        let cookies = parse_cookies(&component_parts.headers);
+        let host = request_host(&component_parts);
+
         Ok(ParsedRequest {
             url_path,
+            query_params,
             component_parts,
+            client_addr,
+            cookies,
+            host,
+            body_bytes,
             body_json,
+            body_form,
+            body_multipart,
         })
     }
 
+    /// request with body replaced such as by websocket message payload
+    ///
+    /// body is parsed as json when possible. form and multipart are not
+    pub fn with_body(&self, body: Bytes) -> Self {
+        let mut ret = self.clone();
+        ret.body_json = serde_json::from_slice::<Value>(&body).ok();
+        ret.body_form = None;
+        ret.body_multipart = None;
+        ret.body_bytes = Some(body);
+        ret
+    }
+
     /// print out logs
     pub fn capture_in_log(&self, verbose: VerboseConfig) {
         // server log (timestamp)
@@ -162,6 +225,29 @@ impl ParsedRequest {
 
                 is_verbose_body = true;
             }
+
+            if let Some(body_form) = self.body_form.as_ref() {
+                printed.push_str("   [request.body.form]");
+                for (key, value) in body_form.iter() {
+                    printed.push_str(&format!(
+                        "\n{}",
+                        style(format!("{}={}", key, value)).green()
+                    ));
+                }
+                is_verbose_body = true;
+            }
+
+            if let Some(body_multipart) = self.body_multipart.as_ref() {
+                printed.push_str("   [request.body.multipart]");
+                for part in body_multipart.iter() {
+                    let s = match part.filename.as_ref() {
+                        Some(filename) => format!("{} (file: {})", part.name, filename),
+                        None => format!("{}={}", part.name, part.content_str()),
+                    };
+                    printed.push_str(&format!("\n{}", style(s).green()));
+                }
+                is_verbose_body = true;
+            }
         }
         if verbose.header || is_verbose_body {
             printed.push_str("\n");
@@ -1,5 +1,13 @@
+pub mod cache_response;
+pub mod compressed_response;
 pub mod error_response;
+pub mod fault_response;
 pub mod file_response;
+pub mod json_response;
+pub mod sse_response;
 pub mod status_code_response;
+pub mod template;
 pub mod text_response;
+pub mod throttled_response;
 mod util;
+pub mod websocket_response;
@@ -0,0 +1,231 @@
+use http_body_util::{BodyExt, Empty};
+use hyper::{
+    header::{
+        HeaderValue, CACHE_CONTROL, CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE, ETAG,
+        IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+    },
+    HeaderMap, Method, StatusCode,
+};
+
+use tokio::{fs::File, io::AsyncReadExt};
+
+use std::{
+    collections::HashMap,
+    io,
+    path::PathBuf,
+    sync::{Mutex, OnceLock},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::core::{
+    config::service_config::cache_config::CacheConfig,
+    server::{constant::FILE_STREAM_CHUNK_BYTES, types::BoxBody},
+};
+
+use super::fault_response::Fault;
+
+#[cfg(test)]
+mod tests;
+
+/// marker in response extensions that `cache-control` is the default one
+#[derive(Clone, Copy, Debug)]
+pub struct DefaultCacheControl;
+
+/// file in response extensions whose content is served as it is
+#[derive(Clone, Debug)]
+pub struct FileSource(pub PathBuf);
+
+/// response with cache-control policy and validators applied
+///
+/// `304 Not Modified` is returned when the request validators match the file
+pub async fn cache_response(
+    response: hyper::Response<BoxBody>,
+    cache_config: &CacheConfig,
+    method: &Method,
+    request_headers: &HeaderMap,
+) -> hyper::Response<BoxBody> {
+    let mut response = response;
+
+    if let Some(cache_control) = cache_config.cache_control.as_ref() {
+        if response.extensions().get::<DefaultCacheControl>().is_some() {
+            if let Ok(cache_control) = HeaderValue::from_str(cache_control.as_str()) {
+                response.headers_mut().insert(CACHE_CONTROL, cache_control);
+            }
+        }
+    }
+
+    if !(cache_config.etag || cache_config.last_modified) || !response.status().is_success() {
+        return response;
+    }
+    // broken response is never validated so as not to turn into `304 Not Modified`
+    if response.extensions().get::<Fault>().is_some() {
+        return response;
+    }
+    let file_path = match response.extensions().get::<FileSource>() {
+        Some(FileSource(x)) => x.clone(),
+        None => return response,
+    };
+
+    let validators = match Validators::from_file(file_path.clone(), cache_config).await {
+        Ok(x) => x,
+        Err(err) => {
+            log::warn!(
+                "{}: failed to get cache validators - {}",
+                file_path.to_string_lossy(),
+                err
+            );
+            return response;
+        }
+    };
+
+    let headers = response.headers_mut();
+    if let Some(etag) = validators.etag.as_ref() {
+        if let Ok(etag) = HeaderValue::from_str(etag.as_str()) {
+            headers.insert(ETAG, etag);
+        }
+    }
+    if let Some(last_modified) = validators.last_modified {
+        if let Ok(last_modified) = HeaderValue::from_str(&httpdate::fmt_http_date(last_modified)) {
+            headers.insert(LAST_MODIFIED, last_modified);
+        }
+    }
+
+    if (method == Method::GET || method == Method::HEAD) && validators.not_modified(request_headers)
+    {
+        not_modified_response(response)
+    } else {
+        response
+    }
+}
+
+/// validators of file to compare with conditional request headers
+struct Validators {
+    etag: Option<String>,
+    last_modified: Option<SystemTime>,
+}
+
+impl Validators {
+    /// get validators enabled by config
+    async fn from_file(file_path: PathBuf, cache_config: &CacheConfig) -> io::Result<Self> {
+        let metadata = tokio::fs::metadata(file_path.as_path()).await?;
+        let modified = metadata.modified()?;
+
+        let last_modified = if cache_config.last_modified {
+            // http date has no sub-second precision
+            let secs = modified
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            Some(UNIX_EPOCH + Duration::from_secs(secs))
+        } else {
+            None
+        };
+
+        let etag = if cache_config.etag {
+            let hash = content_hash(file_path, metadata.len(), modified).await?;
+            Some(format!("\"{:016x}\"", hash))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            etag,
+            last_modified,
+        })
+    }
+
+    /// check if client has the same representation
+    ///
+    /// `if-modified-since` is ignored when `if-none-match` is sent
+    fn not_modified(&self, request_headers: &HeaderMap) -> bool {
+        if let Some(if_none_match) = request_headers.get(IF_NONE_MATCH) {
+            let etag = match self.etag.as_ref() {
+                Some(x) => x,
+                None => return false,
+            };
+            return if_none_match
+                .to_str()
+                .unwrap_or_default()
+                .split(',')
+                .map(|x| x.trim())
+                .any(|x| x == "*" || x.strip_prefix("W/").unwrap_or(x) == etag);
+        }
+
+        match (
+            self.last_modified,
+            request_headers
+                .get(IF_MODIFIED_SINCE)
+                .and_then(|x| x.to_str().ok())
+                .and_then(|x| httpdate::parse_http_date(x).ok()),
+        ) {
+            (Some(last_modified), Some(if_modified_since)) => last_modified <= if_modified_since,
+            _ => false,
+        }
+    }
+}
+
+/// content hash of file kept until its size or modified time changes
+struct ContentHash {
+    len: u64,
+    modified: SystemTime,
+    hash: u64,
+}
+
+static CONTENT_HASHES: OnceLock<Mutex<HashMap<PathBuf, ContentHash>>> = OnceLock::new();
+
+/// content hash of file, read only when file is new or changed
+async fn content_hash(file_path: PathBuf, len: u64, modified: SystemTime) -> io::Result<u64> {
+    let content_hashes = CONTENT_HASHES.get_or_init(Default::default);
+
+    if let Ok(content_hashes) = content_hashes.lock() {
+        if let Some(x) = content_hashes
+            .get(&file_path)
+            .filter(|x| x.len == len && x.modified == modified)
+        {
+            return Ok(x.hash);
+        }
+    }
+
+    let mut file = File::open(file_path.as_path()).await?;
+    let mut hash = FNV_OFFSET_BASIS;
+    let mut buf = vec![0; FILE_STREAM_CHUNK_BYTES];
+    loop {
+        let n = file.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        hash = fnv1a(hash, &buf[..n]);
+    }
+
+    if let Ok(mut content_hashes) = content_hashes.lock() {
+        content_hashes.insert(
+            file_path,
+            ContentHash {
+                len,
+                modified,
+                hash,
+            },
+        );
+    }
+    Ok(hash)
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// 64-bit fnv-1a, stable across rust releases unlike std hasher
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .fold(hash, |hash, x| (hash ^ *x as u64).wrapping_mul(FNV_PRIME))
+}
+
+/// `304 Not Modified` keeping headers but ones describing body
+fn not_modified_response(response: hyper::Response<BoxBody>) -> hyper::Response<BoxBody> {
+    let (mut parts, _) = response.into_parts();
+    parts.status = StatusCode::NOT_MODIFIED;
+    parts.headers.remove(CONTENT_LENGTH);
+    parts.headers.remove(CONTENT_RANGE);
+    parts.headers.remove(CONTENT_TYPE);
+    hyper::Response::from_parts(parts, Empty::new().map_err(|never| match never {}).boxed())
+}
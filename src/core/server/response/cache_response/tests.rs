@@ -0,0 +1,37 @@
+use super::*;
+
+#[test]
+fn fnv1a_stable() {
+    assert_eq!(fnv1a(FNV_OFFSET_BASIS, b""), 0xcbf2_9ce4_8422_2325);
+    assert_eq!(fnv1a(FNV_OFFSET_BASIS, b"a"), 0xaf63_dc4c_8601_ec8c);
+    assert_eq!(
+        fnv1a(fnv1a(FNV_OFFSET_BASIS, b"foo"), b"bar"),
+        fnv1a(FNV_OFFSET_BASIS, b"foobar")
+    );
+}
+
+#[tokio::test]
+async fn content_hash_follows_content() {
+    let file_path =
+        std::env::temp_dir().join(format!("apimock-content-hash-{}", std::process::id()));
+    tokio::fs::write(&file_path, "abc").await.unwrap();
+    let modified = tokio::fs::metadata(&file_path)
+        .await
+        .unwrap()
+        .modified()
+        .unwrap();
+
+    let hash = content_hash(file_path.clone(), 3, modified).await.unwrap();
+    assert_eq!(hash, fnv1a(FNV_OFFSET_BASIS, b"abc"));
+
+    // same size and modified time are regarded as unchanged
+    tokio::fs::write(&file_path, "xyz").await.unwrap();
+    let cached = content_hash(file_path.clone(), 3, modified).await.unwrap();
+    assert_eq!(cached, hash);
+
+    let changed = content_hash(file_path.clone(), 3, modified + Duration::from_secs(1))
+        .await
+        .unwrap();
+    let _ = tokio::fs::remove_file(&file_path).await;
+    assert_eq!(changed, fnv1a(FNV_OFFSET_BASIS, b"xyz"));
+}
@@ -0,0 +1,387 @@
+use flate2::{
+    write::{GzEncoder, ZlibEncoder},
+    Compression,
+};
+use http_body_util::{BodyExt, Empty, Full};
+use hyper::{
+    body::{Body, Bytes, Frame, SizeHint},
+    header::{
+        HeaderValue, ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_TYPE, ETAG, VARY,
+    },
+    HeaderMap, StatusCode,
+};
+
+use std::{
+    io::{self, Write},
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+#[cfg(test)]
+mod tests;
+
+use super::{fault_response::Fault, throttled_response::Throttled};
+use crate::core::server::{constant::TEXT_FILE_MAX_BYTES, types::BoxBody};
+
+/// brotli quality balanced between speed and ratio
+const BROTLI_QUALITY: u32 = 5;
+/// brotli window size in bits
+const BROTLI_LGWIN: u32 = 22;
+const BROTLI_BUFFER_BYTES: usize = 4096;
+
+/// marker in response extensions that compression is already decided
+#[derive(Clone, Copy, Debug)]
+pub struct CompressionHandled;
+
+/// content coding supported to compress response body
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum ContentCoding {
+    Br,
+    Gzip,
+    Deflate,
+}
+
+impl ContentCoding {
+    /// content coding preferred by client in `accept-encoding`
+    ///
+    /// br, gzip and deflate are preferred in the order when their weights are the same
+    pub fn negotiate(accept_encoding: &str) -> Option<Self> {
+        let mut ret: Option<(Self, f32)> = None;
+        let mut wildcard_q: Option<f32> = None;
+        let mut explicit = vec![];
+
+        for item in accept_encoding.split(',') {
+            let mut params = item.split(';').map(|x| x.trim());
+            let name = params.next().unwrap_or_default().to_ascii_lowercase();
+            let q = params
+                .find_map(|x| x.strip_prefix("q=").and_then(|x| x.parse::<f32>().ok()))
+                .unwrap_or(1.0);
+
+            let coding = match name.as_str() {
+                "br" => Self::Br,
+                "gzip" | "x-gzip" => Self::Gzip,
+                "deflate" => Self::Deflate,
+                "*" => {
+                    wildcard_q = Some(q);
+                    continue;
+                }
+                _ => continue,
+            };
+            explicit.push(coding);
+            if 0.0 < q && Self::is_preferred(coding, q, ret) {
+                ret = Some((coding, q));
+            }
+        }
+
+        if let Some(q) = wildcard_q.filter(|x| 0.0 < *x) {
+            let unlisted = [Self::Br, Self::Gzip, Self::Deflate]
+                .into_iter()
+                .find(|x| !explicit.contains(x));
+            if let Some(coding) = unlisted {
+                if Self::is_preferred(coding, q, ret) {
+                    ret = Some((coding, q));
+                }
+            }
+        }
+
+        ret.map(|(coding, _)| coding)
+    }
+
+    /// check if coding with weight is preferred to current choice
+    fn is_preferred(coding: Self, q: f32, current: Option<(Self, f32)>) -> bool {
+        current.map_or(true, |(current_coding, current_q)| {
+            current_q < q || (current_q == q && coding < current_coding)
+        })
+    }
+
+    /// `content-encoding` header value
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Br => "br",
+            Self::Gzip => "gzip",
+            Self::Deflate => "deflate",
+        }
+    }
+}
+
+impl std::fmt::Display for ContentCoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// response whose body is compressed with content coding accepted by client
+///
+/// body of known size is compressed at once to keep `content-length`. body streamed or
+/// throttled is compressed chunk by chunk instead.
+/// response already compressed, handled by rule or broken by fault is kept as it is
+pub async fn compressed_response(
+    response: hyper::Response<BoxBody>,
+    request_headers: &HeaderMap,
+) -> hyper::Response<BoxBody> {
+    if response.extensions().get::<CompressionHandled>().is_some() {
+        return response;
+    }
+    // compressing broken body would lose its fault such as content-length of truncate
+    if response.extensions().get::<Fault>().is_some() {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    parts.extensions.insert(CompressionHandled);
+
+    if !is_compressible(&parts.status, &parts.headers) {
+        return hyper::Response::from_parts(parts, body);
+    }
+
+    append_vary(&mut parts.headers);
+
+    let content_coding = match request_headers
+        .get(ACCEPT_ENCODING)
+        .and_then(|x| x.to_str().ok())
+        .and_then(ContentCoding::negotiate)
+    {
+        Some(x) => x,
+        None => return hyper::Response::from_parts(parts, body),
+    };
+
+    let at_once = parts.extensions.get::<Throttled>().is_none()
+        && body
+            .size_hint()
+            .exact()
+            .is_some_and(|x| x <= TEXT_FILE_MAX_BYTES);
+
+    let body = if at_once {
+        let compressed = match body.collect().await {
+            Ok(collected) => {
+                let mut encoder = Encoder::new(content_coding);
+                encoder
+                    .write(collected.to_bytes().as_ref())
+                    .and_then(|head| encoder.finish().map(|tail| [head, tail].concat()))
+            }
+            Err(err) => Err(err),
+        };
+        match compressed {
+            Ok(compressed) => {
+                parts
+                    .headers
+                    .insert(CONTENT_LENGTH, HeaderValue::from(compressed.len()));
+                Full::new(Bytes::from(compressed))
+                    .map_err(|never| match never {})
+                    .boxed()
+            }
+            Err(err) => {
+                log::error!("failed to compress response body: {}", err);
+                parts.status = StatusCode::INTERNAL_SERVER_ERROR;
+                parts.headers.insert(CONTENT_LENGTH, HeaderValue::from(0));
+                return hyper::Response::from_parts(
+                    parts,
+                    Empty::new().map_err(|never| match never {}).boxed(),
+                );
+            }
+        }
+    } else {
+        parts.headers.remove(CONTENT_LENGTH);
+        BoxBody::new(CompressedBody::new(body, content_coding))
+    };
+
+    parts.headers.insert(
+        CONTENT_ENCODING,
+        HeaderValue::from_static(content_coding.as_str()),
+    );
+    weaken_etag(&mut parts.headers);
+
+    hyper::Response::from_parts(parts, body)
+}
+
+/// check if response body is worth compressing
+///
+/// text-like content types only because media and archives are compressed already
+fn is_compressible(status: &StatusCode, headers: &HeaderMap) -> bool {
+    if matches!(
+        *status,
+        StatusCode::NO_CONTENT | StatusCode::PARTIAL_CONTENT | StatusCode::NOT_MODIFIED
+    ) || headers.contains_key(CONTENT_ENCODING)
+    {
+        return false;
+    }
+
+    let content_type = headers
+        .get(CONTENT_TYPE)
+        .and_then(|x| x.to_str().ok())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    content_type.starts_with("text/")
+        || ["json", "xml", "javascript"]
+            .iter()
+            .any(|x| content_type.contains(x))
+}
+
+/// add `accept-encoding` to `vary` unless it varies on everything
+fn append_vary(headers: &mut HeaderMap) {
+    let vary = match headers.get(VARY).and_then(|x| x.to_str().ok()) {
+        Some("*") => return,
+        Some(x) if !x.is_empty() => format!("{}, {}", x, ACCEPT_ENCODING.as_str()),
+        _ => ACCEPT_ENCODING.as_str().to_owned(),
+    };
+    if let Ok(vary) = HeaderValue::from_str(vary.as_str()) {
+        headers.insert(VARY, vary);
+    }
+}
+
+/// strong etag of content is no longer byte-identical after compression
+fn weaken_etag(headers: &mut HeaderMap) {
+    let etag = match headers.get(ETAG).and_then(|x| x.to_str().ok()) {
+        Some(x) if !x.starts_with("W/") => format!("W/{}", x),
+        _ => return,
+    };
+    if let Ok(etag) = HeaderValue::from_str(etag.as_str()) {
+        headers.insert(ETAG, etag);
+    }
+}
+
+/// compressor writing into buffer drained on every write
+enum Encoder {
+    Br(Box<brotli::CompressorWriter<Vec<u8>>>),
+    Gzip(GzEncoder<Vec<u8>>),
+    Deflate(ZlibEncoder<Vec<u8>>),
+}
+
+impl Encoder {
+    fn new(content_coding: ContentCoding) -> Self {
+        match content_coding {
+            ContentCoding::Br => Self::Br(Box::new(brotli::CompressorWriter::new(
+                vec![],
+                BROTLI_BUFFER_BYTES,
+                BROTLI_QUALITY,
+                BROTLI_LGWIN,
+            ))),
+            ContentCoding::Gzip => Self::Gzip(GzEncoder::new(vec![], Compression::default())),
+            // http `deflate` is zlib format
+            ContentCoding::Deflate => {
+                Self::Deflate(ZlibEncoder::new(vec![], Compression::default()))
+            }
+        }
+    }
+
+    /// compress data and take output so far
+    fn write(&mut self, data: &[u8]) -> io::Result<Vec<u8>> {
+        let ret = match self {
+            Self::Br(x) => {
+                x.write_all(data)?;
+                std::mem::take(x.get_mut())
+            }
+            Self::Gzip(x) => {
+                x.write_all(data)?;
+                std::mem::take(x.get_mut())
+            }
+            Self::Deflate(x) => {
+                x.write_all(data)?;
+                std::mem::take(x.get_mut())
+            }
+        };
+        Ok(ret)
+    }
+
+    /// flush pending data so that client can decode data received so far
+    fn flush(&mut self) -> io::Result<Vec<u8>> {
+        let ret = match self {
+            Self::Br(x) => {
+                x.flush()?;
+                std::mem::take(x.get_mut())
+            }
+            Self::Gzip(x) => {
+                x.flush()?;
+                std::mem::take(x.get_mut())
+            }
+            Self::Deflate(x) => {
+                x.flush()?;
+                std::mem::take(x.get_mut())
+            }
+        };
+        Ok(ret)
+    }
+
+    /// end compressed stream and take the rest of output
+    fn finish(self) -> io::Result<Vec<u8>> {
+        match self {
+            Self::Br(x) => Ok(x.into_inner()),
+            Self::Gzip(x) => x.finish(),
+            Self::Deflate(x) => x.finish(),
+        }
+    }
+}
+
+/// body compressed chunk by chunk
+struct CompressedBody {
+    inner: BoxBody,
+    encoder: Option<Encoder>,
+}
+
+impl CompressedBody {
+    fn new(inner: BoxBody, content_coding: ContentCoding) -> Self {
+        Self {
+            inner,
+            encoder: Some(Encoder::new(content_coding)),
+        }
+    }
+}
+
+impl Body for CompressedBody {
+    type Data = Bytes;
+    type Error = io::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.get_mut();
+
+        loop {
+            let encoder = match this.encoder.as_mut() {
+                Some(x) => x,
+                None => return Poll::Ready(None),
+            };
+
+            match ready!(Pin::new(&mut this.inner).poll_frame(cx)) {
+                Some(Ok(frame)) => {
+                    let data = match frame.into_data() {
+                        Ok(x) => x,
+                        // trailers are not expected in mock responses
+                        Err(_) => continue,
+                    };
+                    let compressed = encoder.write(data.as_ref()).and_then(|mut head| {
+                        encoder.flush().map(|tail| {
+                            head.extend(tail);
+                            head
+                        })
+                    });
+                    match compressed {
+                        Ok(x) if x.is_empty() => continue,
+                        Ok(x) => return Poll::Ready(Some(Ok(Frame::data(Bytes::from(x))))),
+                        Err(err) => return Poll::Ready(Some(Err(err))),
+                    }
+                }
+                Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+                None => {
+                    let tail = this.encoder.take().map(|x| x.finish());
+                    return match tail {
+                        Some(Ok(x)) if !x.is_empty() => {
+                            Poll::Ready(Some(Ok(Frame::data(Bytes::from(x)))))
+                        }
+                        Some(Err(err)) => Poll::Ready(Some(Err(err))),
+                        _ => Poll::Ready(None),
+                    };
+                }
+            }
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.encoder.is_none()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        SizeHint::default()
+    }
+}
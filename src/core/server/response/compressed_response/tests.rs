@@ -0,0 +1,47 @@
+use super::*;
+
+#[test]
+fn negotiate_preference() {
+    assert_eq!(
+        ContentCoding::negotiate("gzip, deflate, br"),
+        Some(ContentCoding::Br)
+    );
+    assert_eq!(
+        ContentCoding::negotiate("deflate, gzip"),
+        Some(ContentCoding::Gzip)
+    );
+    assert_eq!(
+        ContentCoding::negotiate("deflate"),
+        Some(ContentCoding::Deflate)
+    );
+}
+
+#[test]
+fn negotiate_weight() {
+    assert_eq!(
+        ContentCoding::negotiate("br;q=0.5, gzip;q=0.8"),
+        Some(ContentCoding::Gzip)
+    );
+    assert_eq!(
+        ContentCoding::negotiate("br;q=0, gzip"),
+        Some(ContentCoding::Gzip)
+    );
+    assert_eq!(ContentCoding::negotiate("gzip;q=0"), None);
+}
+
+#[test]
+fn negotiate_wildcard() {
+    assert_eq!(ContentCoding::negotiate("*"), Some(ContentCoding::Br));
+    assert_eq!(
+        ContentCoding::negotiate("br;q=0, *"),
+        Some(ContentCoding::Gzip)
+    );
+    assert_eq!(ContentCoding::negotiate("*;q=0"), None);
+}
+
+#[test]
+fn negotiate_unsupported() {
+    assert_eq!(ContentCoding::negotiate("identity"), None);
+    assert_eq!(ContentCoding::negotiate("zstd"), None);
+    assert_eq!(ContentCoding::negotiate(""), None);
+}
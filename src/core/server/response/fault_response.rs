@@ -0,0 +1,200 @@
+use http_body_util::BodyExt;
+use hyper::{
+    body::{Body, Bytes, Frame},
+    header::CONTENT_LENGTH,
+};
+use serde::Deserialize;
+use tokio::time::Sleep;
+
+use std::{
+    future::Future,
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use crate::core::server::types::BoxBody;
+
+/// wait before aborting so that the first half of body reaches client
+const ABORT_DELAY_MILLISECONDS: u64 = 100;
+
+/// network failure injected into response
+#[derive(Clone, Copy, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Fault {
+    /// close connection without response
+    Close,
+    /// send headers and the first half of body, then reset connection
+    Reset,
+    /// send the first half of body with content-length of whole body, then close connection
+    Truncate,
+    /// send headers, then never send body
+    Stall,
+}
+
+impl std::fmt::Display for Fault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Close => write!(f, "close"),
+            Self::Reset => write!(f, "reset"),
+            Self::Truncate => write!(f, "truncate"),
+            Self::Stall => write!(f, "stall"),
+        }
+    }
+}
+
+/// error to abort response or connection on purpose
+#[derive(Debug)]
+pub struct FaultError(pub Fault);
+
+impl std::fmt::Display for FaultError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "fault injected: {}", self.0)
+    }
+}
+
+impl std::error::Error for FaultError {}
+
+/// check if error is caused by fault injection
+pub fn is_fault_error(err: &(dyn std::error::Error + 'static)) -> bool {
+    let mut source = Some(err);
+    while let Some(err) = source {
+        if err.is::<FaultError>() {
+            return true;
+        }
+        // io error hides its inner error from source
+        let inner = err.downcast_ref::<io::Error>().and_then(|x| x.get_ref());
+        if inner.is_some_and(|x| x.is::<FaultError>()) {
+            return true;
+        }
+        source = err.source();
+    }
+    false
+}
+
+/// response generated as usual, broken by fault
+///
+/// fault is also put into response extensions so that connection handles `close` and `reset`
+pub async fn fault_response(
+    response: hyper::Response<BoxBody>,
+    fault: Fault,
+) -> hyper::Response<BoxBody> {
+    let (mut parts, body) = response.into_parts();
+
+    // body length is known in advance so that body is forwarded without being held
+    let body_len = body.size_hint().exact().or_else(|| {
+        parts
+            .headers
+            .get(CONTENT_LENGTH)
+            .and_then(|x| x.to_str().ok())
+            .and_then(|x| x.parse::<u64>().ok())
+    });
+
+    let fault_body = match fault {
+        Fault::Close | Fault::Stall => FaultBody::new(None, None, fault),
+        Fault::Reset => {
+            // chunked so that body ends without the terminating chunk
+            parts.headers.remove(CONTENT_LENGTH);
+            FaultBody::new(Some(body), body_len.map(|x| x / 2), fault)
+        }
+        Fault::Truncate => FaultBody::new(Some(body), body_len.map(|x| x / 2), fault),
+    };
+
+    parts.extensions.insert(fault);
+    hyper::Response::from_parts(parts, fault_body.boxed())
+}
+
+/// body which ends according to fault after forwarding data
+struct FaultBody {
+    inner: Option<BoxBody>,
+    /// bytes to forward before cut-off. only the first data frame when unknown
+    remaining: Option<u64>,
+    fault: Fault,
+    abort_delay: Option<Pin<Box<Sleep>>>,
+}
+
+impl FaultBody {
+    fn new(inner: Option<BoxBody>, cut_off: Option<u64>, fault: Fault) -> Self {
+        Self {
+            inner: inner.filter(|_| cut_off != Some(0)),
+            remaining: cut_off,
+            fault,
+            abort_delay: None,
+        }
+    }
+
+    /// forward data frames of inner body until cut-off
+    fn poll_inner(&mut self, cx: &mut Context<'_>) -> Poll<Option<Bytes>> {
+        while let Some(inner) = self.inner.as_mut() {
+            let frame = match Pin::new(inner).poll_frame(cx) {
+                Poll::Ready(Some(Ok(x))) => x,
+                Poll::Ready(Some(Err(err))) => {
+                    log::warn!("failed to read response body to inject fault: {}", err);
+                    break;
+                }
+                Poll::Ready(None) => break,
+                Poll::Pending => return Poll::Pending,
+            };
+
+            // trailers are not sent on broken body
+            let Ok(mut data) = frame.into_data() else {
+                continue;
+            };
+            if data.is_empty() {
+                continue;
+            }
+
+            match self.remaining.as_mut() {
+                Some(remaining) => {
+                    if (data.len() as u64) >= *remaining {
+                        data.truncate(*remaining as usize);
+                        self.inner = None;
+                    }
+                    *remaining -= data.len() as u64;
+                }
+                None => self.inner = None,
+            }
+            return Poll::Ready(Some(data));
+        }
+
+        self.inner = None;
+        Poll::Ready(None)
+    }
+}
+
+impl Body for FaultBody {
+    type Data = Bytes;
+    type Error = io::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.get_mut();
+
+        match this.poll_inner(cx) {
+            Poll::Ready(Some(data)) => return Poll::Ready(Some(Ok(Frame::data(data)))),
+            Poll::Ready(None) => (),
+            Poll::Pending => return Poll::Pending,
+        }
+
+        match this.fault {
+            Fault::Close => Poll::Ready(None),
+            Fault::Stall => Poll::Pending,
+            Fault::Reset | Fault::Truncate => {
+                let abort_delay = this.abort_delay.get_or_insert_with(|| {
+                    Box::pin(tokio::time::sleep(Duration::from_millis(
+                        ABORT_DELAY_MILLISECONDS,
+                    )))
+                });
+                match abort_delay.as_mut().poll(cx) {
+                    Poll::Ready(()) => {
+                        Poll::Ready(Some(Err(io::Error::other(FaultError(this.fault)))))
+                    }
+                    Poll::Pending => Poll::Pending,
+                }
+            }
+        }
+    }
+}
@@ -1,24 +1,43 @@
-use hyper::HeaderMap;
+use http_body_util::{BodyExt, Full};
+use hyper::{
+    body::Bytes,
+    header::{ACCEPT_RANGES, CONTENT_ENCODING, CONTENT_RANGE, RANGE},
+    HeaderMap, StatusCode,
+};
 use serde_json::{Map, Value};
-use tokio::task;
+use tokio::{
+    fs::File,
+    io::{AsyncReadExt, AsyncSeekExt},
+};
+
+use std::{collections::HashMap, io, io::SeekFrom, path::PathBuf, time::Duration};
 
-use std::{collections::HashMap, fs};
+mod range;
+mod stream_body;
 
 use crate::core::{
     server::{
-        constant::CSV_RECORDS_DEFAULT_KEY, response::error_response::not_found_response,
-        response_handler::ResponseHandler, types::BoxBody,
+        constant::{CSV_RECORDS_DEFAULT_KEY, TEXT_FILE_MAX_BYTES, TEXT_FILE_SNIFF_BYTES},
+        response::error_response::not_found_response,
+        response_handler::ResponseHandler,
+        types::BoxBody,
     },
     util::json::resolve_with_json_compatible_extensions,
 };
 
 use super::{
+    cache_response::FileSource,
     error_response::internal_server_error_response,
-    text_response::text_response,
+    template::TemplateContext,
     util::{
-        binary_content_type, file_extension, json_value_with_jsonpath_key, text_file_content_type,
+        binary_content_type, file_content_type, file_extension, json_value_with_jsonpath_key,
+        precompressed_content_type, text_file_content_type, BINARY_DEFAULT_CONTENT_TYPE,
     },
 };
+use range::{parse_range, ByteRange, RangeRequest};
+use stream_body::{ChainBody, FileStreamBody, RecordStreamBody};
+
+const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
 
 pub struct FileResponse {
     file_path: String,
@@ -27,6 +46,14 @@ pub struct FileResponse {
     binary_content: Option<Vec<u8>>,
     custom_headers: Option<HashMap<String, Option<String>>>,
     request_headers: HeaderMap,
+    /// render text and json content as template when set
+    template_context: Option<TemplateContext>,
+    /// http status code other than 200 OK
+    status_code: Option<StatusCode>,
+    /// interval between ndjson records streamed
+    stream_interval: Option<Duration>,
+    /// stream csv rows as ndjson instead of records object
+    csv_as_ndjson: bool,
 }
 
 impl FileResponse {
@@ -43,6 +70,10 @@ impl FileResponse {
             binary_content: None,
             custom_headers: custom_headers.cloned(),
             request_headers: request_headers.clone(),
+            template_context: None,
+            status_code: None,
+            stream_interval: None,
+            csv_as_ndjson: false,
         }
     }
 
@@ -58,10 +89,50 @@ impl FileResponse {
         ret
     }
 
+    /// render content as template with request data
+    pub fn with_template(mut self, template_context: TemplateContext) -> Self {
+        self.template_context = Some(template_context);
+        self
+    }
+
+    /// respond with custom http status code
+    pub fn with_status(mut self, status_code: Option<StatusCode>) -> Self {
+        self.status_code = status_code;
+        self
+    }
+
+    /// wait between ndjson records streamed
+    pub fn with_stream_interval(mut self, interval_milliseconds: Option<u64>) -> Self {
+        self.stream_interval = interval_milliseconds.map(Duration::from_millis);
+        self
+    }
+
+    /// stream csv rows as ndjson records
+    pub fn with_csv_as_ndjson(mut self, csv_as_ndjson: bool) -> Self {
+        self.csv_as_ndjson = csv_as_ndjson;
+        self
+    }
+
     /// response from file path
+    ///
+    /// file path is kept in response extensions for cache validators unless rendered as template
     pub async fn file_content_response(
         &mut self,
     ) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
+        let response = self.content_response().await;
+        match self.template_context.as_ref() {
+            Some(_) => response,
+            None => response.map(|mut response| {
+                response
+                    .extensions_mut()
+                    .insert(FileSource(PathBuf::from(self.file_path.as_str())));
+                response
+            }),
+        }
+    }
+
+    /// response from file content
+    async fn content_response(&mut self) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
         let file_path = match resolve_with_json_compatible_extensions(self.file_path.as_str()) {
             Some(x) => x,
             None => {
@@ -74,49 +145,193 @@ impl FileResponse {
         };
         self.file_path = file_path.clone();
 
-        // read file as text file in non-blocking task
-        let file_path_to_read_text_file = file_path.clone();
-        let content =
-            task::spawn_blocking(move || fs::read_to_string(file_path_to_read_text_file)).await;
+        match file_extension(self.file_path.as_str()).as_deref() {
+            // pre-compressed file is sent as it is
+            Some("gz") => return self.stream_content_response().await,
+            Some("ndjson" | "jsonl") => return self.ndjson_file_content_response().await,
+            _ => (),
+        }
+
+        match self.is_text_file().await {
+            Ok(true) => (),
+            Ok(false) => return self.stream_content_response().await,
+            Err(err) => {
+                return internal_server_error_response(
+                    &format!("{}: failed to read file - {}", self.file_path, err),
+                    &self.request_headers,
+                )
+            }
+        }
+
+        // read file at once as content is used as text
+        let content = match tokio::fs::read(file_path.as_str()).await {
+            Ok(x) => x,
+            Err(err) => {
+                return internal_server_error_response(
+                    &format!("{}: failed to read file - {}", self.file_path, err),
+                    &self.request_headers,
+                )
+            }
+        };
 
-        let response = match content {
-            Ok(Ok(content)) => {
+        match String::from_utf8(content) {
+            Ok(content) => {
                 self.text_content = Some(content);
                 self.text_file_content_response()
             }
-            Ok(Err(_)) => {
-                // read file as binary in non-blocking task
-                let file_path_to_read_binary = file_path.clone();
-                let content =
-                    task::spawn_blocking(move || fs::read(file_path_to_read_binary)).await;
-                match content {
-                    Ok(Ok(content)) => {
-                        self.binary_content = Some(content);
-                        self.binary_content_type_response()
-                    }
-                    Ok(Err(err)) => {
-                        return internal_server_error_response(
-                            &format!("{}: failed to read file - {}", self.file_path, err),
-                            &self.request_headers,
-                        )
-                    }
-                    Err(err) => {
-                        return internal_server_error_response(
-                            &format!("{}: async task failed - {}", self.file_path, err),
-                            &self.request_headers,
-                        )
-                    }
-                }
+            Err(err) => {
+                self.binary_content = Some(err.into_bytes());
+                self.binary_content_type_response()
+            }
+        }
+    }
+
+    /// check if file is read as text, or streamed as binary
+    ///
+    /// json and csv are always text to transform. known binary file types are not.
+    /// the others are text when small enough and its head is valid utf-8
+    async fn is_text_file(&self) -> io::Result<bool> {
+        match file_extension(self.file_path.as_str()).as_deref() {
+            Some("json" | "json5" | "csv") => return Ok(true),
+            _ if binary_content_type(self.file_path.as_str()) != BINARY_DEFAULT_CONTENT_TYPE => {
+                return Ok(false)
             }
+            _ => (),
+        }
+
+        let mut file = File::open(self.file_path.as_str()).await?;
+        if TEXT_FILE_MAX_BYTES < file.metadata().await?.len() {
+            return Ok(false);
+        }
+
+        let mut head = Vec::with_capacity(TEXT_FILE_SNIFF_BYTES);
+        (&mut file)
+            .take(TEXT_FILE_SNIFF_BYTES as u64)
+            .read_to_end(&mut head)
+            .await?;
+        let ret = match std::str::from_utf8(head.as_slice()) {
+            Ok(_) => true,
+            // multibyte char may be cut at the end of head
+            Err(err) => err.error_len().is_none(),
+        };
+        Ok(ret)
+    }
+
+    /// binary file response streamed from disk
+    ///
+    /// `range` request header is respected unless custom status code is set
+    async fn stream_content_response(
+        &self,
+    ) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
+        let opened = match File::open(self.file_path.as_str()).await {
+            Ok(file) => file.metadata().await.map(|x| (file, x.len())),
+            Err(err) => Err(err),
+        };
+        let (file, total) = match opened {
+            Ok(x) => x,
             Err(err) => {
                 return internal_server_error_response(
-                    &format!("{}: async task failed - {}", self.file_path, err),
+                    &format!("{}: failed to read file - {}", self.file_path, err),
                     &self.request_headers,
                 )
             }
         };
 
-        response
+        let (content_type, content_encoding) =
+            match precompressed_content_type(self.file_path.as_str()) {
+                Some(content_type) => (content_type, Some("gzip")),
+                None => (file_content_type(self.file_path.as_str()), None),
+            };
+        let range_request = match self.status_code {
+            Some(_) => RangeRequest::Full,
+            None => parse_range(
+                self.request_headers
+                    .get(RANGE)
+                    .and_then(|x| x.to_str().ok()),
+                total,
+            ),
+        };
+
+        let mut response_handler = self
+            .response_handler()
+            .with_header(ACCEPT_RANGES.as_str(), Some("bytes"));
+        if let Some(content_encoding) = content_encoding {
+            response_handler =
+                response_handler.with_header(CONTENT_ENCODING.as_str(), Some(content_encoding));
+        }
+
+        let ranges = match range_request {
+            RangeRequest::Full => {
+                return response_handler
+                    .with_stream_body(
+                        BoxBody::new(FileStreamBody::new(file, total)),
+                        Some(content_type),
+                    )
+                    .into_response(&self.request_headers)
+            }
+            RangeRequest::Unsatisfiable => {
+                return response_handler
+                    .with_status(&StatusCode::RANGE_NOT_SATISFIABLE)
+                    .with_header(CONTENT_RANGE.as_str(), Some(format!("bytes */{}", total)))
+                    .into_response(&self.request_headers)
+            }
+            RangeRequest::Partial(ranges) => ranges,
+        };
+
+        let response_handler = response_handler.with_status(&StatusCode::PARTIAL_CONTENT);
+        let partial = if let [range] = ranges.as_slice() {
+            file_range_body(file, range).await.map(|body| {
+                response_handler
+                    .with_header(CONTENT_RANGE.as_str(), Some(range.content_range(total)))
+                    .with_stream_body(body, Some(content_type))
+            })
+        } else {
+            self.multipart_ranges_body(ranges.as_slice(), content_type.as_str(), total)
+                .await
+                .map(|(body, multipart_content_type)| {
+                    response_handler.with_stream_body(body, Some(multipart_content_type))
+                })
+        };
+
+        match partial {
+            Ok(response_handler) => response_handler.into_response(&self.request_headers),
+            Err(err) => internal_server_error_response(
+                &format!("{}: failed to read file range - {}", self.file_path, err),
+                &self.request_headers,
+            ),
+        }
+    }
+
+    /// `multipart/byteranges` body and its content type
+    async fn multipart_ranges_body(
+        &self,
+        ranges: &[ByteRange],
+        content_type: &str,
+        total: u64,
+    ) -> io::Result<(BoxBody, String)> {
+        let boundary = format!("apimock-{:016x}", rand::random::<u64>());
+
+        let mut bodies = vec![];
+        for range in ranges {
+            let part_headers = format!(
+                "--{}\r\ncontent-type: {}\r\ncontent-range: {}\r\n\r\n",
+                boundary,
+                content_type,
+                range.content_range(total)
+            );
+            bodies.push(bytes_body(part_headers));
+
+            let file = File::open(self.file_path.as_str()).await?;
+            bodies.push(file_range_body(file, range).await?);
+
+            bodies.push(bytes_body("\r\n".to_owned()));
+        }
+        bodies.push(bytes_body(format!("--{}--\r\n", boundary)));
+
+        Ok((
+            BoxBody::new(ChainBody::new(bodies)),
+            format!("multipart/byteranges; boundary={}", boundary),
+        ))
     }
 
     /// text file response
@@ -124,27 +339,36 @@ impl FileResponse {
         match file_extension(self.file_path.as_str()) {
             Some(ext) => match ext.as_str() {
                 "json" | "json5" => self.json_file_content_response(),
+                "csv" if self.csv_as_ndjson => self.csv_ndjson_content_response(),
                 "csv" => self.csv_file_content_response(),
-                _ => text_response(
-                    self.text_content.clone().unwrap_or_default().as_str(),
+                _ => self.text_content_type_response(
+                    self.rendered_text_content().as_str(),
                     Some(text_file_content_type(ext).as_str()),
-                    None,
-                    &self.request_headers,
                 ),
             },
-            None => text_response(
-                self.text_content.clone().unwrap_or_default().as_str(),
-                None,
-                None,
-                &self.request_headers,
-            ),
+            None => self.text_content_type_response(self.rendered_text_content().as_str(), None),
+        }
+    }
+
+    /// text content rendered as template if required
+    fn rendered_text_content(&self) -> String {
+        let text_content = self.text_content.clone().unwrap_or_default();
+        match self.template_context.as_ref() {
+            Some(template_context) => template_context.render_text(text_content.as_str()),
+            None => text_content,
         }
     }
 
     /// json file response
     fn json_file_content_response(&self) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
         match json5::from_str::<Value>(self.text_content.clone().unwrap_or_default().as_str()) {
-            Ok(content) => self.json_content_type_response(content.to_string().as_str()),
+            Ok(content) => {
+                let content = match self.template_context.as_ref() {
+                    Some(template_context) => template_context.render_json(content),
+                    None => content,
+                };
+                self.json_content_type_response(content.to_string().as_str())
+            }
             _ => internal_server_error_response(
                 &format!("{}: invalid json content", self.file_path.as_str()),
                 &self.request_headers,
@@ -152,24 +376,59 @@ impl FileResponse {
         }
     }
 
-    /// csv file response
-    fn csv_file_content_response(&self) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
+    /// ndjson file response streamed record by record
+    ///
+    /// file is read line by line while sent so that it is not held in memory at once
+    async fn ndjson_file_content_response(
+        &self,
+    ) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
+        let file = match File::open(self.file_path.as_str()).await {
+            Ok(x) => x,
+            Err(err) => {
+                return internal_server_error_response(
+                    &format!("{}: failed to read file - {}", self.file_path, err),
+                    &self.request_headers,
+                )
+            }
+        };
+
+        let file_path = self.file_path.clone();
+        let template_context = self.template_context.clone();
+        let to_record = move |line: &str| {
+            let record = serde_json::from_str::<Value>(line)
+                .map_err(|err| format!("{}: invalid json - {}", file_path, err))?;
+            let record = match template_context.as_ref() {
+                Some(template_context) => template_context.render_json(record),
+                None => record,
+            };
+            Ok(Bytes::from(format!("{}\n", record)))
+        };
+
+        self.response_handler()
+            .with_stream_body(
+                BoxBody::new(RecordStreamBody::from_lines(
+                    file,
+                    to_record,
+                    self.stream_interval,
+                )),
+                Some(NDJSON_CONTENT_TYPE),
+            )
+            .into_response(&self.request_headers)
+    }
+
+    /// csv rows as json objects keyed by header
+    fn csv_rows(&self) -> Result<Vec<Value>, String> {
         let text_content = self.text_content.clone().unwrap_or_default();
         let mut rdr = csv::ReaderBuilder::new()
             .has_headers(true)
             .from_reader(text_content.as_bytes());
 
-        let csv_headers = if let Ok(csv_headers) = rdr.headers() {
-            csv_headers.clone()
-        } else {
-            return internal_server_error_response(
-                &format!("{}: failed to analyze csv headers", self.file_path.as_str()),
-                &self.request_headers,
-            );
+        let csv_headers = match rdr.headers() {
+            Ok(csv_headers) => csv_headers.clone(),
+            Err(_) => return Err("failed to analyze csv headers".to_owned()),
         };
 
-        let rows = rdr
-            .records()
+        rdr.records()
             .map(|result| {
                 let record = result?;
                 let obj = csv_headers
@@ -179,9 +438,24 @@ impl FileResponse {
                     .collect::<Map<_, _>>();
                 Ok(Value::Object(obj))
             })
-            .collect::<Result<Vec<Value>, csv::Error>>();
+            .collect::<Result<Vec<Value>, csv::Error>>()
+            .map_err(|err| format!("failed to analyze csv records - {}", err))
+    }
 
-        match rows {
+    /// csv file response streamed as ndjson row by row
+    fn csv_ndjson_content_response(&self) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
+        match self.csv_rows() {
+            Ok(rows) => self.ndjson_content_type_response(rows),
+            Err(err) => internal_server_error_response(
+                &format!("{}: {}", self.file_path.as_str(), err),
+                &self.request_headers,
+            ),
+        }
+    }
+
+    /// csv file response
+    fn csv_file_content_response(&self) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
+        match self.csv_rows() {
             Ok(rows) => {
                 let jsonpath_key = if let Some(csv_records_key) = self.csv_records_key.as_ref() {
                     csv_records_key.as_str()
@@ -204,43 +478,83 @@ impl FileResponse {
                 }
             }
             Err(err) => internal_server_error_response(
-                &format!(
-                    "{}: failed to analyze csv records - {}",
-                    self.file_path.as_str(),
-                    err
-                ),
+                &format!("{}: {}", self.file_path.as_str(), err),
                 &self.request_headers,
             ),
         }
     }
 
-    fn json_content_type_response(
-        &self,
-        body: &str,
-    ) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
+    /// response handler with custom headers and status code
+    fn response_handler(&self) -> ResponseHandler {
         let mut response_handler = ResponseHandler::default();
 
         if let Some(custom_headers) = self.custom_headers.clone() {
             response_handler = response_handler.with_headers(custom_headers);
         }
 
+        if let Some(status_code) = self.status_code.as_ref() {
+            response_handler = response_handler.with_status(status_code);
+        }
+
         response_handler
+    }
+
+    /// text response
+    fn text_content_type_response(
+        &self,
+        body: &str,
+        content_type: Option<&str>,
+    ) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
+        self.response_handler()
+            .with_text(body, content_type)
+            .into_response(&self.request_headers)
+    }
+
+    fn json_content_type_response(
+        &self,
+        body: &str,
+    ) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
+        self.response_handler()
             .with_json_body(body)
             .into_response(&self.request_headers)
     }
 
+    /// ndjson response in chunked transfer with a record per chunk
+    fn ndjson_content_type_response(
+        &self,
+        records: Vec<Value>,
+    ) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
+        let records = records
+            .iter()
+            .map(|x| Bytes::from(format!("{}\n", x)))
+            .collect::<Vec<Bytes>>();
+        self.response_handler()
+            .with_stream_body(
+                BoxBody::new(RecordStreamBody::new(records, self.stream_interval)),
+                Some(NDJSON_CONTENT_TYPE),
+            )
+            .into_response(&self.request_headers)
+    }
+
     /// binary file response
     fn binary_content_type_response(&self) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
-        let mut response_handler = ResponseHandler::default();
-
-        if let Some(custom_headers) = self.custom_headers.clone() {
-            response_handler = response_handler.with_headers(custom_headers);
-        }
-
         let content = self.binary_content.clone().unwrap_or_default().to_owned();
         let content_type = binary_content_type(self.file_path.as_str());
-        response_handler
+        self.response_handler()
             .with_binary_body(content, Some(content_type))
             .into_response(&self.request_headers)
     }
 }
+
+/// body streaming range of file
+async fn file_range_body(mut file: File, range: &ByteRange) -> io::Result<BoxBody> {
+    file.seek(SeekFrom::Start(range.start)).await?;
+    Ok(BoxBody::new(FileStreamBody::new(file, range.len())))
+}
+
+/// body of bytes in memory
+fn bytes_body(s: String) -> BoxBody {
+    Full::new(Bytes::from(s))
+        .map_err(|never| match never {})
+        .boxed()
+}
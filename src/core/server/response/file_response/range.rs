@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests;
+
+/// ranges more than it are ignored to avoid too many parts
+const MAX_RANGES: usize = 16;
+
+/// byte range of file with inclusive start and end
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ByteRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl ByteRange {
+    pub fn len(&self) -> u64 {
+        self.end - self.start + 1
+    }
+
+    /// `content-range` header value
+    pub fn content_range(&self, total: u64) -> String {
+        format!("bytes {}-{}/{}", self.start, self.end, total)
+    }
+}
+
+/// what `range` request header asks for
+#[derive(Debug, PartialEq)]
+pub enum RangeRequest {
+    /// whole content as range is missing or ignored
+    Full,
+    Partial(Vec<ByteRange>),
+    Unsatisfiable,
+}
+
+/// parse `range` header value such as `bytes=0-99,200-,-50` against content of total length
+///
+/// invalid header is ignored. ranges out of content are dropped
+pub fn parse_range(header_value: Option<&str>, total: u64) -> RangeRequest {
+    let specs = match header_value.and_then(|x| x.trim().strip_prefix("bytes=")) {
+        Some(x) => x,
+        None => return RangeRequest::Full,
+    };
+
+    let specs = specs
+        .split(',')
+        .map(|x| x.trim())
+        .filter(|x| !x.is_empty())
+        .collect::<Vec<&str>>();
+    // empty range set is invalid
+    if specs.is_empty() {
+        return RangeRequest::Full;
+    }
+
+    let mut ranges = vec![];
+    for spec in specs {
+        let (start, end) = match spec.split_once('-') {
+            Some(x) => x,
+            None => return RangeRequest::Full,
+        };
+
+        let range = match (start.trim(), end.trim()) {
+            // suffix range: last bytes
+            ("", suffix_len) => match suffix_len.parse::<u64>() {
+                Ok(0) => None,
+                Ok(suffix_len) if 0 < total => Some(ByteRange {
+                    start: total.saturating_sub(suffix_len),
+                    end: total - 1,
+                }),
+                Ok(_) => None,
+                Err(_) => return RangeRequest::Full,
+            },
+            (start, end) => {
+                let start = match start.parse::<u64>() {
+                    Ok(x) => x,
+                    Err(_) => return RangeRequest::Full,
+                };
+                let end = match end {
+                    "" => None,
+                    end => match end.parse::<u64>() {
+                        Ok(x) if start <= x => Some(x),
+                        _ => return RangeRequest::Full,
+                    },
+                };
+                if start < total {
+                    Some(ByteRange {
+                        start,
+                        end: end.unwrap_or(u64::MAX).min(total - 1),
+                    })
+                } else {
+                    None
+                }
+            }
+        };
+        ranges.extend(range);
+    }
+
+    if MAX_RANGES < ranges.len() {
+        return RangeRequest::Full;
+    }
+    if ranges.is_empty() {
+        return RangeRequest::Unsatisfiable;
+    }
+    RangeRequest::Partial(ranges)
+}
@@ -0,0 +1,70 @@
+use super::*;
+
+fn range(start: u64, end: u64) -> ByteRange {
+    ByteRange { start, end }
+}
+
+#[test]
+fn single() {
+    assert_eq!(
+        parse_range(Some("bytes=0-9"), 100),
+        RangeRequest::Partial(vec![range(0, 9)])
+    );
+    assert_eq!(
+        parse_range(Some("bytes=90-"), 100),
+        RangeRequest::Partial(vec![range(90, 99)])
+    );
+    assert_eq!(
+        parse_range(Some("bytes=-10"), 100),
+        RangeRequest::Partial(vec![range(90, 99)])
+    );
+    assert_eq!(
+        parse_range(Some("bytes=50-200"), 100),
+        RangeRequest::Partial(vec![range(50, 99)])
+    );
+    assert_eq!(
+        parse_range(Some("bytes=-200"), 100),
+        RangeRequest::Partial(vec![range(0, 99)])
+    );
+}
+
+#[test]
+fn multi() {
+    assert_eq!(
+        parse_range(Some("bytes=0-9, 20-29,200-300"), 100),
+        RangeRequest::Partial(vec![range(0, 9), range(20, 29)])
+    );
+}
+
+#[test]
+fn unsatisfiable() {
+    assert_eq!(
+        parse_range(Some("bytes=100-"), 100),
+        RangeRequest::Unsatisfiable
+    );
+    assert_eq!(
+        parse_range(Some("bytes=-0"), 100),
+        RangeRequest::Unsatisfiable
+    );
+    assert_eq!(
+        parse_range(Some("bytes=0-"), 0),
+        RangeRequest::Unsatisfiable
+    );
+}
+
+#[test]
+fn ignored() {
+    assert_eq!(parse_range(None, 100), RangeRequest::Full);
+    assert_eq!(parse_range(Some("items=0-9"), 100), RangeRequest::Full);
+    assert_eq!(parse_range(Some("bytes=9-0"), 100), RangeRequest::Full);
+    assert_eq!(parse_range(Some("bytes=a-b"), 100), RangeRequest::Full);
+    assert_eq!(parse_range(Some("bytes=5"), 100), RangeRequest::Full);
+    assert_eq!(parse_range(Some("bytes="), 100), RangeRequest::Full);
+    assert_eq!(parse_range(Some("bytes= , "), 100), RangeRequest::Full);
+}
+
+#[test]
+fn content_range() {
+    assert_eq!(range(0, 9).content_range(100), "bytes 0-9/100");
+    assert_eq!(range(0, 9).len(), 10);
+}
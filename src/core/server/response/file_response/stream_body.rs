@@ -0,0 +1,281 @@
+use hyper::body::{Body, Bytes, Frame, SizeHint};
+use tokio::{
+    fs::File,
+    io::{AsyncBufReadExt, AsyncRead, BufReader, Lines, ReadBuf},
+    time::Sleep,
+};
+
+use std::{
+    collections::VecDeque,
+    future::Future,
+    io,
+    pin::Pin,
+    task::{ready, Context, Poll},
+    time::Duration,
+};
+
+use crate::core::server::{constant::FILE_STREAM_CHUNK_BYTES, types::BoxBody};
+
+#[cfg(test)]
+mod tests;
+
+/// body read from file chunk by chunk with bounded buffer
+pub struct FileStreamBody {
+    file: File,
+    remaining: u64,
+    buf: Vec<u8>,
+}
+
+impl FileStreamBody {
+    /// create instance reading `len` bytes from current position of file
+    pub fn new(file: File, len: u64) -> Self {
+        Self {
+            file,
+            remaining: len,
+            buf: vec![],
+        }
+    }
+}
+
+impl Body for FileStreamBody {
+    type Data = Bytes;
+    type Error = io::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.get_mut();
+
+        if this.remaining == 0 {
+            return Poll::Ready(None);
+        }
+
+        let chunk_len = this.remaining.min(FILE_STREAM_CHUNK_BYTES as u64) as usize;
+        this.buf.resize(chunk_len, 0);
+
+        let mut read_buf = ReadBuf::new(this.buf.as_mut_slice());
+        ready!(Pin::new(&mut this.file).poll_read(cx, &mut read_buf))?;
+        let read_len = read_buf.filled().len();
+        if read_len == 0 {
+            // file got shorter than its length at the start
+            return Poll::Ready(Some(Err(io::Error::from(io::ErrorKind::UnexpectedEof))));
+        }
+
+        this.remaining -= read_len as u64;
+        this.buf.truncate(read_len);
+        Poll::Ready(Some(Ok(Frame::data(Bytes::from(std::mem::take(
+            &mut this.buf,
+        ))))))
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.remaining == 0
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        SizeHint::with_exact(self.remaining)
+    }
+}
+
+/// bodies sent one after another
+pub struct ChainBody {
+    bodies: VecDeque<BoxBody>,
+}
+
+impl ChainBody {
+    pub fn new(bodies: impl IntoIterator<Item = BoxBody>) -> Self {
+        Self {
+            bodies: bodies.into_iter().collect(),
+        }
+    }
+}
+
+impl Body for ChainBody {
+    type Data = Bytes;
+    type Error = io::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.get_mut();
+
+        while let Some(body) = this.bodies.front_mut() {
+            match ready!(Pin::new(body).poll_frame(cx)) {
+                Some(frame) => return Poll::Ready(Some(frame)),
+                None => {
+                    this.bodies.pop_front();
+                }
+            }
+        }
+        Poll::Ready(None)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.bodies.iter().all(|x| x.is_end_stream())
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        let exact = self
+            .bodies
+            .iter()
+            .map(|x| x.size_hint().exact())
+            .sum::<Option<u64>>();
+        match exact {
+            Some(exact) => SizeHint::with_exact(exact),
+            None => {
+                let mut ret = SizeHint::new();
+                ret.set_lower(self.bodies.iter().map(|x| x.size_hint().lower()).sum());
+                ret
+            }
+        }
+    }
+}
+
+/// records sent one per chunk with interval between them
+pub struct RecordStreamBody {
+    source: RecordSource,
+    /// record read in advance so that no wait is left after the last one
+    next: Option<Bytes>,
+    ended: bool,
+    interval: Option<Duration>,
+    /// no wait before the first record
+    started: bool,
+    wait: Option<Pin<Box<Sleep>>>,
+}
+
+/// conversion from line to record
+type ToRecord = Box<dyn Fn(&str) -> Result<Bytes, String> + Send + Sync>;
+
+/// where records come from
+enum RecordSource {
+    Memory(VecDeque<Bytes>),
+    /// file read line by line. blank lines are skipped
+    Lines {
+        lines: Lines<BufReader<File>>,
+        line_number: usize,
+        to_record: ToRecord,
+    },
+}
+
+impl RecordStreamBody {
+    pub fn new(records: impl IntoIterator<Item = Bytes>, interval: Option<Duration>) -> Self {
+        Self::with_source(
+            RecordSource::Memory(records.into_iter().collect()),
+            interval,
+        )
+    }
+
+    /// create instance converting each line of file to record on demand
+    pub fn from_lines(
+        file: File,
+        to_record: impl Fn(&str) -> Result<Bytes, String> + Send + Sync + 'static,
+        interval: Option<Duration>,
+    ) -> Self {
+        let source = RecordSource::Lines {
+            lines: BufReader::new(file).lines(),
+            line_number: 0,
+            to_record: Box::new(to_record),
+        };
+        Self::with_source(source, interval)
+    }
+
+    fn with_source(source: RecordSource, interval: Option<Duration>) -> Self {
+        Self {
+            source,
+            next: None,
+            ended: false,
+            interval: interval.filter(|x| !x.is_zero()),
+            started: false,
+            wait: None,
+        }
+    }
+}
+
+impl RecordSource {
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<io::Result<Bytes>>> {
+        match self {
+            Self::Memory(records) => Poll::Ready(records.pop_front().map(Ok)),
+            Self::Lines {
+                lines,
+                line_number,
+                to_record,
+            } => loop {
+                let line = match ready!(Pin::new(&mut *lines).poll_next_line(cx)) {
+                    Ok(Some(x)) => x,
+                    Ok(None) => return Poll::Ready(None),
+                    Err(err) => return Poll::Ready(Some(Err(err))),
+                };
+                *line_number += 1;
+                if line.trim().is_empty() {
+                    continue;
+                }
+
+                let record = to_record(line.as_str()).map_err(|err| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("line {} - {}", line_number, err),
+                    )
+                });
+                return Poll::Ready(Some(record));
+            },
+        }
+    }
+}
+
+impl Body for RecordStreamBody {
+    type Data = Bytes;
+    type Error = io::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.get_mut();
+
+        if this.ended {
+            return Poll::Ready(None);
+        }
+
+        if this.next.is_none() {
+            match ready!(this.source.poll_next(cx)) {
+                Some(Ok(record)) => this.next = Some(record),
+                Some(Err(err)) => {
+                    log::warn!("failed to read record to stream: {}", err);
+                    this.ended = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+                None => {
+                    this.ended = true;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+
+        if let Some(interval) = this.interval.filter(|_| this.started) {
+            let wait = this
+                .wait
+                .get_or_insert_with(|| Box::pin(tokio::time::sleep(interval)));
+            ready!(wait.as_mut().poll(cx));
+            this.wait = None;
+        }
+
+        this.started = true;
+        match this.next.take() {
+            Some(record) => Poll::Ready(Some(Ok(Frame::data(record)))),
+            None => Poll::Ready(None),
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.ended
+            || (self.next.is_none()
+                && matches!(&self.source, RecordSource::Memory(records) if records.is_empty()))
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        // unknown so that response is sent in chunked transfer
+        SizeHint::default()
+    }
+}
@@ -0,0 +1,82 @@
+use http_body_util::BodyExt;
+
+use std::time::Instant;
+
+use super::*;
+
+#[tokio::test]
+async fn stream_in_chunks() {
+    let content = (0..FILE_STREAM_CHUNK_BYTES * 2 + 100)
+        .map(|x| (x % 251) as u8)
+        .collect::<Vec<u8>>();
+    let file_path =
+        std::env::temp_dir().join(format!("apimock-stream-body-{}", std::process::id()));
+    tokio::fs::write(&file_path, content.as_slice())
+        .await
+        .unwrap();
+
+    let file = File::open(&file_path).await.unwrap();
+    let mut body = FileStreamBody::new(file, content.len() as u64);
+    assert_eq!(body.size_hint().exact(), Some(content.len() as u64));
+
+    let mut frames = vec![];
+    while let Some(frame) = body.frame().await {
+        frames.push(frame.unwrap().into_data().unwrap());
+    }
+    let _ = tokio::fs::remove_file(&file_path).await;
+
+    assert_eq!(
+        frames.iter().map(|x| x.len()).collect::<Vec<usize>>(),
+        vec![FILE_STREAM_CHUNK_BYTES, FILE_STREAM_CHUNK_BYTES, 100]
+    );
+    assert_eq!(frames.concat(), content);
+    assert!(body.is_end_stream());
+}
+
+#[tokio::test]
+async fn stream_records_at_interval() {
+    let records = ["{\"id\":1}\n", "{\"id\":2}\n", "{\"id\":3}\n"].map(Bytes::from);
+    let mut body = RecordStreamBody::new(records.clone(), Some(Duration::from_millis(50)));
+    assert_eq!(body.size_hint().exact(), None);
+
+    let started_at = Instant::now();
+    let mut frames = vec![];
+    while let Some(frame) = body.frame().await {
+        frames.push(frame.unwrap().into_data().unwrap());
+    }
+
+    assert_eq!(frames, records.to_vec());
+    assert!(Duration::from_millis(100) <= started_at.elapsed());
+    assert!(body.is_end_stream());
+}
+
+#[tokio::test]
+async fn stream_records_from_lines() {
+    let file_path =
+        std::env::temp_dir().join(format!("apimock-stream-lines-{}", std::process::id()));
+    tokio::fs::write(&file_path, "a\n\nb\nnot a record\nc\n")
+        .await
+        .unwrap();
+
+    let file = File::open(&file_path).await.unwrap();
+    let to_record = |line: &str| match line.len() {
+        1 => Ok(Bytes::from(format!("{}\n", line.to_uppercase()))),
+        _ => Err("too long".to_owned()),
+    };
+    let mut body = RecordStreamBody::from_lines(file, to_record, None);
+    assert_eq!(body.size_hint().exact(), None);
+
+    let mut frames = vec![];
+    let mut err = None;
+    while let Some(frame) = body.frame().await {
+        match frame {
+            Ok(frame) => frames.push(frame.into_data().unwrap()),
+            Err(x) => err = Some(x),
+        }
+    }
+    let _ = tokio::fs::remove_file(&file_path).await;
+
+    assert_eq!(frames, ["A\n", "B\n"].map(Bytes::from).to_vec());
+    assert_eq!(err.unwrap().to_string(), "line 4 - too long");
+    assert!(body.is_end_stream());
+}
@@ -0,0 +1,25 @@
+use hyper::{HeaderMap, StatusCode};
+use serde_json::Value;
+
+use std::collections::HashMap;
+
+use crate::core::server::{response_handler::ResponseHandler, types::BoxBody};
+
+/// inline json response
+pub fn json_response(
+    content: &Value,
+    status_code: Option<&StatusCode>,
+    custom_headers: Option<&HashMap<String, Option<String>>>,
+    request_headers: &HeaderMap,
+) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
+    let mut response_handler = ResponseHandler::default();
+    if let Some(status_code) = status_code {
+        response_handler = response_handler.with_status(status_code);
+    }
+    if let Some(custom_headers) = custom_headers {
+        response_handler = response_handler.with_headers(custom_headers.to_owned());
+    }
+    response_handler
+        .with_json_body(content.to_string())
+        .into_response(request_headers)
+}
@@ -0,0 +1,330 @@
+use hyper::{
+    body::{Body, Bytes, Frame, SizeHint},
+    header::CACHE_CONTROL,
+    HeaderMap, StatusCode,
+};
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::time::Sleep;
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    io,
+    pin::Pin,
+    task::{ready, Context, Poll},
+    time::Duration,
+};
+
+#[cfg(test)]
+mod tests;
+
+use super::{error_response::internal_server_error_response, util::file_extension};
+use crate::core::server::{response_handler::ResponseHandler, types::BoxBody};
+
+const SSE_CONTENT_TYPE: &str = "text/event-stream";
+
+/// server-sent events emitted one by one on a kept connection
+#[derive(Clone, Deserialize, Debug)]
+pub struct Sse {
+    /// events listed in rule
+    pub events: Option<Vec<SseEvent>>,
+    /// json array or csv file with an event per record
+    pub file_path: Option<String>,
+    /// behavior after the last event is emitted
+    pub end: Option<SseEnd>,
+}
+
+/// event written as `id`, `event`, `retry` and `data` fields
+#[derive(Clone, Default, Deserialize, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct SseEvent {
+    pub id: Option<String>,
+    pub event: Option<String>,
+    pub retry: Option<u64>,
+    /// text as it is, or the other json value serialized
+    pub data: Option<Value>,
+    /// wait before this event is emitted
+    pub delay_milliseconds: Option<u64>,
+}
+
+/// behavior after the last event is emitted
+#[derive(Clone, Copy, Default, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SseEnd {
+    /// keep connection open without events
+    #[default]
+    KeepOpen,
+    /// emit events again from the first one
+    Repeat,
+    /// end response so that client reconnects
+    Close,
+}
+
+impl std::fmt::Display for SseEnd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::KeepOpen => write!(f, "keep_open"),
+            Self::Repeat => write!(f, "repeat"),
+            Self::Close => write!(f, "close"),
+        }
+    }
+}
+
+impl Sse {
+    /// events from rule or file on each request
+    pub async fn load_events(&self, full_file_path: Option<&str>) -> Result<Vec<SseEvent>, String> {
+        match (self.events.as_ref(), full_file_path) {
+            (Some(events), _) => Ok(events.clone()),
+            (None, Some(full_file_path)) => {
+                let content = tokio::fs::read_to_string(full_file_path)
+                    .await
+                    .map_err(|err| {
+                        format!("{}: failed to read sse file - {}", full_file_path, err)
+                    })?;
+                events_from_file_content(full_file_path, content.as_str())
+            }
+            (None, None) => Err("sse requires either of events or file_path".to_owned()),
+        }
+    }
+
+    /// events from rule or file read at once to validate on startup
+    pub fn read_events(&self, full_file_path: Option<&str>) -> Result<Vec<SseEvent>, String> {
+        match (self.events.as_ref(), full_file_path) {
+            (Some(events), _) => Ok(events.clone()),
+            (None, Some(full_file_path)) => {
+                let content = std::fs::read_to_string(full_file_path).map_err(|err| {
+                    format!("{}: failed to read sse file - {}", full_file_path, err)
+                })?;
+                events_from_file_content(full_file_path, content.as_str())
+            }
+            (None, None) => Err("sse requires either of events or file_path".to_owned()),
+        }
+    }
+
+    /// validate loaded events
+    pub fn validate(&self, events: &[SseEvent]) -> Result<(), String> {
+        if self.events.is_some() && self.file_path.is_some() {
+            return Err("sse cannot set both events and file_path".to_owned());
+        }
+        if events.is_empty() {
+            return Err("sse requires at least one event".to_owned());
+        }
+        if let Some(idx) = events.iter().position(|x| x.is_empty()) {
+            return Err(format!(
+                "sse event #{} requires at least either of id, event, retry or data",
+                idx + 1
+            ));
+        }
+        // line break would start another field or event
+        if let Some(idx) = events.iter().position(|x| x.has_line_break()) {
+            return Err(format!(
+                "sse event #{} cannot have line break in id or event",
+                idx + 1
+            ));
+        }
+        let has_delay = events
+            .iter()
+            .any(|x| x.delay_milliseconds.is_some_and(|x| 0 < x));
+        if self.end == Some(SseEnd::Repeat) && !has_delay {
+            return Err("sse repeat requires positive delay_milliseconds in events".to_owned());
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Sse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.events.as_ref(), self.file_path.as_ref()) {
+            (Some(events), _) => write!(f, "{} events", events.len())?,
+            (None, Some(file_path)) => write!(f, "`{}`", file_path)?,
+            (None, None) => (),
+        }
+        write!(f, ", {}", self.end.unwrap_or_default())
+    }
+}
+
+impl SseEvent {
+    /// check if no field is set
+    fn is_empty(&self) -> bool {
+        self.id.is_none() && self.event.is_none() && self.retry.is_none() && self.data.is_none()
+    }
+
+    /// check if single-line field has line break
+    fn has_line_break(&self) -> bool {
+        [self.id.as_ref(), self.event.as_ref()]
+            .into_iter()
+            .flatten()
+            .any(|x| x.contains(['\n', '\r']))
+    }
+
+    /// event stream format ending with blank line
+    pub fn to_bytes(&self) -> Bytes {
+        let mut ret = String::new();
+        if let Some(id) = self.id.as_ref() {
+            ret.push_str(format!("id: {}\n", id).as_str());
+        }
+        if let Some(event) = self.event.as_ref() {
+            ret.push_str(format!("event: {}\n", event).as_str());
+        }
+        if let Some(retry) = self.retry {
+            ret.push_str(format!("retry: {}\n", retry).as_str());
+        }
+        let data = match self.data.as_ref() {
+            Some(Value::String(x)) => Some(x.to_owned()),
+            Some(x) => Some(x.to_string()),
+            None => None,
+        };
+        if let Some(data) = data {
+            // multiline data is split into data fields
+            for line in data.split('\n') {
+                ret.push_str(format!("data: {}\n", line.trim_end_matches('\r')).as_str());
+            }
+        }
+        ret.push('\n');
+        Bytes::from(ret)
+    }
+}
+
+/// events from json array or csv records
+fn events_from_file_content(file_path: &str, content: &str) -> Result<Vec<SseEvent>, String> {
+    match file_extension(file_path).as_deref() {
+        Some("json" | "json5") => json5::from_str::<Vec<SseEvent>>(content)
+            .map_err(|err| format!("{}: sse file must be array of events - {}", file_path, err)),
+        Some("csv") => events_from_csv(content)
+            .map_err(|err| format!("{}: failed to analyze sse csv - {}", file_path, err)),
+        _ => Err(format!(
+            "{}: sse file must be json, json5 or csv",
+            file_path
+        )),
+    }
+}
+
+/// events from csv whose header has field names
+fn events_from_csv(content: &str) -> Result<Vec<SseEvent>, String> {
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(content.as_bytes());
+    let csv_headers = rdr.headers().map_err(|err| err.to_string())?.clone();
+
+    rdr.records()
+        .map(|record| {
+            let record = record.map_err(|err| err.to_string())?;
+            let fields = csv_headers
+                .iter()
+                .zip(record.iter())
+                .filter(|(_, v)| !v.is_empty())
+                .collect::<HashMap<&str, &str>>();
+            let number = |key: &str| -> Result<Option<u64>, String> {
+                fields
+                    .get(key)
+                    .map(|x| {
+                        x.parse::<u64>()
+                            .map_err(|err| format!("{} must be number: {} ({})", key, x, err))
+                    })
+                    .transpose()
+            };
+            Ok(SseEvent {
+                id: fields.get("id").map(|x| x.to_string()),
+                event: fields.get("event").map(|x| x.to_string()),
+                retry: number("retry")?,
+                data: fields.get("data").map(|x| Value::String(x.to_string())),
+                delay_milliseconds: number("delay_milliseconds")?,
+            })
+        })
+        .collect()
+}
+
+/// event stream response
+pub fn sse_response(
+    events: Result<Vec<SseEvent>, String>,
+    end: SseEnd,
+    status_code: Option<&StatusCode>,
+    custom_headers: Option<&HashMap<String, Option<String>>>,
+    request_headers: &HeaderMap,
+) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
+    let events = match events {
+        Ok(x) => x,
+        Err(err) => return internal_server_error_response(err.as_str(), request_headers),
+    };
+
+    let mut response_handler =
+        ResponseHandler::default().with_header(CACHE_CONTROL.as_str(), Some("no-cache"));
+    if let Some(custom_headers) = custom_headers {
+        response_handler = response_handler.with_headers(custom_headers.clone());
+    }
+    if let Some(status_code) = status_code {
+        response_handler = response_handler.with_status(status_code);
+    }
+
+    response_handler
+        .with_stream_body(
+            BoxBody::new(SseBody::new(events, end)),
+            Some(SSE_CONTENT_TYPE),
+        )
+        .into_response(request_headers)
+}
+
+/// body emitting events after their delays
+struct SseBody {
+    events: Vec<SseEvent>,
+    end: SseEnd,
+    next: usize,
+    wait: Option<Pin<Box<Sleep>>>,
+}
+
+impl SseBody {
+    fn new(events: Vec<SseEvent>, end: SseEnd) -> Self {
+        Self {
+            events,
+            end,
+            next: 0,
+            wait: None,
+        }
+    }
+}
+
+impl Body for SseBody {
+    type Data = Bytes;
+    type Error = io::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.get_mut();
+
+        if this.events.len() <= this.next {
+            match this.end {
+                SseEnd::KeepOpen => return Poll::Pending,
+                SseEnd::Close => return Poll::Ready(None),
+                SseEnd::Repeat => this.next = 0,
+            }
+        }
+        let event = match this.events.get(this.next) {
+            Some(x) => x,
+            None => return Poll::Ready(None),
+        };
+
+        if let Some(delay_milliseconds) = event.delay_milliseconds.filter(|x| 0 < *x) {
+            let wait = this.wait.get_or_insert_with(|| {
+                Box::pin(tokio::time::sleep(Duration::from_millis(
+                    delay_milliseconds,
+                )))
+            });
+            ready!(wait.as_mut().poll(cx));
+            this.wait = None;
+        }
+
+        this.next += 1;
+        Poll::Ready(Some(Ok(Frame::data(event.to_bytes()))))
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.end == SseEnd::Close && self.events.len() <= self.next
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        SizeHint::default()
+    }
+}
@@ -0,0 +1,116 @@
+use super::*;
+
+#[test]
+fn event_fields() {
+    let event = SseEvent {
+        id: Some("1".to_owned()),
+        event: Some("message".to_owned()),
+        retry: Some(3000),
+        data: Some(Value::String("hello".to_owned())),
+        delay_milliseconds: Some(100),
+    };
+    assert_eq!(
+        event.to_bytes(),
+        "id: 1\nevent: message\nretry: 3000\ndata: hello\n\n"
+    );
+}
+
+#[test]
+fn event_data() {
+    let multiline = SseEvent {
+        data: Some(Value::String("first\nsecond".to_owned())),
+        ..Default::default()
+    };
+    assert_eq!(multiline.to_bytes(), "data: first\ndata: second\n\n");
+
+    let json = SseEvent {
+        data: Some(serde_json::json!({"id": 1})),
+        ..Default::default()
+    };
+    assert_eq!(json.to_bytes(), "data: {\"id\":1}\n\n");
+}
+
+#[test]
+fn events_from_json() {
+    let events = events_from_file_content(
+        "events.json",
+        r#"[{ "id": "1", "data": { "count": 1 } }, { "event": "done", "delay_milliseconds": 10 }]"#,
+    )
+    .unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].data, Some(serde_json::json!({"count": 1})));
+    assert_eq!(events[1].delay_milliseconds, Some(10));
+}
+
+#[test]
+fn events_from_csv_records() {
+    let events = events_from_file_content(
+        "events.csv",
+        "id,event,data,delay_milliseconds\n1,,hello,\n2,update,world,50\n",
+    )
+    .unwrap();
+    assert_eq!(
+        events,
+        vec![
+            SseEvent {
+                id: Some("1".to_owned()),
+                data: Some(Value::String("hello".to_owned())),
+                ..Default::default()
+            },
+            SseEvent {
+                id: Some("2".to_owned()),
+                event: Some("update".to_owned()),
+                data: Some(Value::String("world".to_owned())),
+                delay_milliseconds: Some(50),
+                ..Default::default()
+            },
+        ]
+    );
+
+    assert!(events_from_file_content("events.csv", "id,retry\n1,soon\n").is_err());
+}
+
+#[test]
+fn validate_repeat() {
+    let sse = Sse {
+        events: None,
+        file_path: None,
+        end: Some(SseEnd::Repeat),
+    };
+    let event = SseEvent {
+        data: Some(Value::String("tick".to_owned())),
+        ..Default::default()
+    };
+    assert!(sse.validate(std::slice::from_ref(&event)).is_err());
+
+    let delayed = SseEvent {
+        delay_milliseconds: Some(1000),
+        ..event
+    };
+    assert!(sse.validate(&[delayed]).is_ok());
+}
+
+#[test]
+fn validate_line_break() {
+    let sse = Sse {
+        events: None,
+        file_path: None,
+        end: None,
+    };
+    for (id, event) in [(Some("1\ndata: injected"), None), (None, Some("update\r"))] {
+        let event = SseEvent {
+            id: id.map(|x| x.to_owned()),
+            event: event.map(|x| x.to_owned()),
+            data: Some(Value::String("hello".to_owned())),
+            ..Default::default()
+        };
+        assert!(sse.validate(&[event]).is_err());
+    }
+}
+
+#[test]
+fn unknown_event_field() {
+    assert!(
+        events_from_file_content("events.json", r#"[{ "data": "hello", "delay": 500 }]"#).is_err()
+    );
+}
@@ -0,0 +1,317 @@
+use hyper::HeaderMap;
+use rand::Rng;
+use serde_json::Value;
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::core::{
+    server::{parsed_request::ParsedRequest, routing::rule_set::rule::Captures},
+    util::json::{json_value_by_jsonpath, json_value_str, jsonpath::JsonPath},
+};
+
+#[cfg(test)]
+mod tests;
+
+const PLACEHOLDER_START: &str = "{{";
+const PLACEHOLDER_END: &str = "}}";
+
+/// request data available in response template
+///
+/// placeholders are written as `{{ expr }}`:
+/// - `path.<name>`: path parameter or named capture
+/// - `query.<name>`, `headers.<name>`, `cookies.<name>`: request value by name
+/// - `body`, `body.<jsonpath>`: json request body (or raw body as text) and its field
+/// - `method`, `url_path`, `host`, `client_ip`
+/// - `now()`, `timestamp()`, `uuid()`, `random_int(min, max)`
+#[derive(Clone, Debug, Default)]
+pub struct TemplateContext {
+    pub url_path: String,
+    pub method: String,
+    pub host: Option<String>,
+    pub client_ip: String,
+    pub query_params: Vec<(String, String)>,
+    pub headers: HeaderMap,
+    pub cookies: Vec<(String, String)>,
+    pub body_json: Option<Value>,
+    pub body_text: Option<String>,
+    pub captures: Captures,
+}
+
+impl TemplateContext {
+    /// create instance from request
+    pub fn new(parsed_request: &ParsedRequest, captures: &Captures) -> Self {
+        let body_text = parsed_request
+            .body_bytes
+            .as_ref()
+            .and_then(|x| std::str::from_utf8(x).ok())
+            .map(|x| x.to_owned());
+
+        Self {
+            url_path: parsed_request.url_path.clone(),
+            method: parsed_request.component_parts.method.to_string(),
+            host: parsed_request.host.clone(),
+            client_ip: parsed_request.client_addr.ip().to_canonical().to_string(),
+            query_params: parsed_request.query_params.clone(),
+            headers: parsed_request.component_parts.headers.clone(),
+            cookies: parsed_request.cookies.clone(),
+            body_json: parsed_request.body_json.clone(),
+            body_text,
+            captures: captures.clone(),
+        }
+    }
+
+    /// render text by replacing placeholders. missing value is replaced with empty string
+    pub fn render_text(&self, s: &str) -> String {
+        let mut ret = String::with_capacity(s.len());
+        let mut rest = s;
+        while let Some((before, expr, after)) = next_placeholder(rest) {
+            ret.push_str(before);
+            if let Some(value) = self.evaluate(expr) {
+                ret.push_str(json_value_str(&value).as_str());
+            }
+            rest = after;
+        }
+        ret.push_str(rest);
+        ret
+    }
+
+    /// render json strings (including object keys) recursively
+    ///
+    /// string consisting only of a placeholder is replaced with the value keeping its json type
+    pub fn render_json(&self, value: Value) -> Value {
+        match value {
+            Value::String(s) => match whole_placeholder(s.as_str()) {
+                Some(expr) => self.evaluate(expr).unwrap_or(Value::Null),
+                None => Value::String(self.render_text(s.as_str())),
+            },
+            Value::Array(arr) => {
+                Value::Array(arr.into_iter().map(|x| self.render_json(x)).collect())
+            }
+            Value::Object(map) => Value::Object(
+                map.into_iter()
+                    .map(|(k, v)| (self.render_text(k.as_str()), self.render_json(v)))
+                    .collect(),
+            ),
+            x => x,
+        }
+    }
+
+    /// value of expression
+    fn evaluate(&self, expr: &str) -> Option<Value> {
+        let ret = match Expr::parse(expr) {
+            Ok(Expr::Path(name)) => self.captures.get(name).cloned().map(Value::String),
+            Ok(Expr::Query(name)) => self
+                .query_params
+                .iter()
+                .find(|(k, _)| k == name)
+                .map(|(_, v)| Value::String(v.to_owned())),
+            Ok(Expr::Headers(name)) => self
+                .headers
+                .get(name)
+                .and_then(|x| x.to_str().ok())
+                .map(|x| Value::String(x.to_owned())),
+            Ok(Expr::Cookies(name)) => self
+                .cookies
+                .iter()
+                .find(|(k, _)| k == name)
+                .map(|(_, v)| Value::String(v.to_owned())),
+            Ok(Expr::Body(None)) => match self.body_json.as_ref() {
+                Some(x) => Some(x.to_owned()),
+                None => self.body_text.clone().map(Value::String),
+            },
+            Ok(Expr::Body(Some(jsonpath))) => self
+                .body_json
+                .as_ref()
+                .and_then(|x| json_value_by_jsonpath(x, jsonpath))
+                .cloned(),
+            Ok(Expr::Method) => Some(Value::String(self.method.clone())),
+            Ok(Expr::UrlPath) => Some(Value::String(self.url_path.clone())),
+            Ok(Expr::Host) => self.host.clone().map(Value::String),
+            Ok(Expr::ClientIp) => Some(Value::String(self.client_ip.clone())),
+            Ok(Expr::Now) => Some(Value::String(rfc3339_now())),
+            Ok(Expr::Timestamp) => Some(Value::from(unix_now())),
+            Ok(Expr::Uuid) => Some(Value::String(uuid_v4())),
+            Ok(Expr::RandomInt(min, max)) => Some(Value::from(rand::rng().random_range(min..=max))),
+            Err(err) => {
+                log::warn!("{}", err);
+                None
+            }
+        };
+
+        if ret.is_none() {
+            log::debug!("template `{}` has no value", expr.trim());
+        }
+        ret
+    }
+}
+
+/// check if all placeholders in text are valid
+pub fn validate(s: &str) -> Result<(), String> {
+    let mut rest = s;
+    while let Some((_, expr, after)) = next_placeholder(rest) {
+        if let Expr::Body(Some(jsonpath)) = Expr::parse(expr)? {
+            JsonPath::parse(jsonpath)?;
+        }
+        rest = after;
+    }
+    Ok(())
+}
+
+/// check if all placeholders in json strings (including object keys) are valid
+pub fn validate_json(value: &Value) -> Result<(), String> {
+    match value {
+        Value::String(s) => validate(s),
+        Value::Array(arr) => arr.iter().try_for_each(validate_json),
+        Value::Object(map) => map
+            .iter()
+            .try_for_each(|(k, v)| validate(k).and_then(|_| validate_json(v))),
+        _ => Ok(()),
+    }
+}
+
+/// template expression
+enum Expr<'a> {
+    Path(&'a str),
+    Query(&'a str),
+    Headers(&'a str),
+    Cookies(&'a str),
+    Body(Option<&'a str>),
+    Method,
+    UrlPath,
+    Host,
+    ClientIp,
+    Now,
+    Timestamp,
+    Uuid,
+    RandomInt(i64, i64),
+}
+
+impl<'a> Expr<'a> {
+    fn parse(expr: &'a str) -> Result<Self, String> {
+        let expr = expr.trim();
+
+        if let Some((name, args)) = function_call(expr) {
+            let ret = match (name, args.as_slice()) {
+                ("now", []) => Self::Now,
+                ("timestamp", []) => Self::Timestamp,
+                ("uuid", []) => Self::Uuid,
+                ("random_int", [min, max]) => match (min.parse::<i64>(), max.parse::<i64>()) {
+                    (Ok(min), Ok(max)) if min <= max => Self::RandomInt(min, max),
+                    _ => {
+                        return Err(format!(
+                            "invalid template `{}`: random_int requires min and max integers",
+                            expr
+                        ))
+                    }
+                },
+                _ => return Err(format!("invalid template `{}`: unknown function", expr)),
+            };
+            return Ok(ret);
+        }
+
+        let ret = match expr.split_once('.') {
+            Some(("path", name)) if !name.is_empty() => Self::Path(name),
+            Some(("query", name)) if !name.is_empty() => Self::Query(name),
+            Some(("headers", name)) if !name.is_empty() => Self::Headers(name),
+            Some(("cookies", name)) if !name.is_empty() => Self::Cookies(name),
+            Some(("body", jsonpath)) if !jsonpath.is_empty() => Self::Body(Some(jsonpath)),
+            None => match expr {
+                "body" => Self::Body(None),
+                "method" => Self::Method,
+                "url_path" => Self::UrlPath,
+                "host" => Self::Host,
+                "client_ip" => Self::ClientIp,
+                _ => return Err(format!("invalid template `{}`: unknown name", expr)),
+            },
+            _ => return Err(format!("invalid template `{}`: unknown name", expr)),
+        };
+        Ok(ret)
+    }
+}
+
+/// function name and trimmed args such as `random_int(1, 10)`
+fn function_call(expr: &str) -> Option<(&str, Vec<&str>)> {
+    let (name, args) = expr.strip_suffix(')')?.split_once('(')?;
+    let args = args
+        .split(',')
+        .map(|x| x.trim())
+        .filter(|x| !x.is_empty())
+        .collect();
+    Some((name.trim(), args))
+}
+
+/// text before placeholder, expression and text after placeholder
+fn next_placeholder(s: &str) -> Option<(&str, &str, &str)> {
+    let start = s.find(PLACEHOLDER_START)?;
+    let expr_start = start + PLACEHOLDER_START.len();
+    let end = s[expr_start..].find(PLACEHOLDER_END)? + expr_start;
+    Some((
+        &s[..start],
+        &s[expr_start..end],
+        &s[end + PLACEHOLDER_END.len()..],
+    ))
+}
+
+/// expression when text consists only of a placeholder
+fn whole_placeholder(s: &str) -> Option<&str> {
+    match next_placeholder(s.trim()) {
+        Some(("", expr, "")) => Some(expr),
+        _ => None,
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|x| x.as_secs())
+        .unwrap_or_default()
+}
+
+/// current utc datetime such as `2025-01-31T12:34:56Z`
+fn rfc3339_now() -> String {
+    let secs = unix_now();
+    let (year, month, day) = civil_from_days((secs / 86400) as i64);
+    let secs_of_day = secs % 86400;
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        (secs_of_day / 60) % 60,
+        secs_of_day % 60
+    )
+}
+
+/// date from days since unix epoch (proleptic gregorian calendar)
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// random uuid version 4
+fn uuid_v4() -> String {
+    let mut bytes: [u8; 16] = rand::rng().random();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = bytes
+        .iter()
+        .map(|x| format!("{:02x}", x))
+        .collect::<String>();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
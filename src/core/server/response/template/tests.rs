@@ -0,0 +1,114 @@
+use hyper::header::{HeaderValue, USER_AGENT};
+use serde_json::json;
+
+use super::*;
+
+fn context() -> TemplateContext {
+    let mut headers = HeaderMap::new();
+    headers.insert(USER_AGENT, HeaderValue::from_static("curl/8"));
+
+    TemplateContext {
+        url_path: "/users/12".to_owned(),
+        method: "POST".to_owned(),
+        host: Some("example.com".to_owned()),
+        client_ip: "127.0.0.1".to_owned(),
+        query_params: vec![("page".to_owned(), "2".to_owned())],
+        headers,
+        cookies: vec![("session".to_owned(), "abc".to_owned())],
+        body_json: Some(json!({"user": {"name": "alice", "age": 20}})),
+        body_text: None,
+        captures: [("id".to_owned(), "12".to_owned())].into_iter().collect(),
+    }
+}
+
+#[test]
+fn render_text() {
+    let context = context();
+    assert_eq!(
+        context.render_text("{{ method }} {{url_path}} id={{ path.id }} page={{ query.page }}"),
+        "POST /users/12 id=12 page=2"
+    );
+    assert_eq!(
+        context.render_text("{{ headers.user-agent }} {{ cookies.session }} {{ host }}"),
+        "curl/8 abc example.com"
+    );
+    assert_eq!(
+        context.render_text("{{ body.user.name }} ({{ body.$.user.age }})"),
+        "alice (20)"
+    );
+    assert_eq!(context.render_text("[{{ query.missing }}]"), "[]");
+    assert_eq!(
+        context.render_text("no placeholder {{"),
+        "no placeholder {{"
+    );
+}
+
+#[test]
+fn render_json() {
+    let context = context();
+    let rendered = context.render_json(json!({
+        "id": "{{ path.id }}",
+        "name": "{{ body.user.name }}",
+        "age": "{{ body.user.age }}",
+        "label": "user {{ body.user.name }}",
+        "missing": "{{ body.user.email }}",
+        "list": ["{{ query.page }}"],
+    }));
+    assert_eq!(
+        rendered,
+        json!({
+            "id": "12",
+            "name": "alice",
+            "age": 20,
+            "label": "user alice",
+            "missing": null,
+            "list": ["2"],
+        })
+    );
+}
+
+#[test]
+fn helpers() {
+    let context = context();
+
+    let uuid = context.render_text("{{ uuid() }}");
+    assert_eq!(uuid.len(), 36);
+    assert_eq!(&uuid[14..15], "4");
+
+    let n = context
+        .render_text("{{ random_int(1, 3) }}")
+        .parse::<i64>()
+        .unwrap();
+    assert!((1..=3).contains(&n));
+
+    let now = context.render_text("{{ now() }}");
+    assert_eq!(now.len(), "2025-01-31T12:34:56Z".len());
+    assert!(now.ends_with('Z'));
+
+    assert!(context.render_json(json!("{{ timestamp() }}")).is_u64());
+}
+
+#[test]
+fn civil_date() {
+    assert_eq!(civil_from_days(0), (1970, 1, 1));
+    assert_eq!(civil_from_days(19_753), (2024, 1, 31));
+    assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+}
+
+#[test]
+fn validate_expressions() {
+    assert!(validate("{{ path.id }} {{ uuid() }} {{ random_int(-1, 1) }}").is_ok());
+    assert!(validate("{{ unknown }}").is_err());
+    assert!(validate("{{ query. }}").is_err());
+    assert!(validate("{{ random_int(3, 1) }}").is_err());
+    assert!(validate("{{ nope() }}").is_err());
+    assert!(validate("{{ body.$.items[0].id }}").is_ok());
+    assert!(validate("{{ body.$.items[ }}").is_err());
+}
+
+#[test]
+fn validate_json_expressions() {
+    assert!(validate_json(&json!({"id": "{{ path.id }}", "{{ query.key }}": [1, "x"]})).is_ok());
+    assert!(validate_json(&json!({"list": ["{{ unknown }}"]})).is_err());
+    assert!(validate_json(&json!({"{{ nope() }}": 1})).is_err());
+}
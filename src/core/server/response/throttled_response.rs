@@ -0,0 +1,114 @@
+use hyper::body::{Body, Bytes, Frame, SizeHint};
+use tokio::time::Sleep;
+
+use std::{
+    future::Future,
+    io,
+    pin::Pin,
+    task::{ready, Context, Poll},
+    time::Duration,
+};
+
+use crate::core::server::types::BoxBody;
+
+/// body chunks are sent this many times per second
+const CHUNKS_PER_SECOND: u64 = 10;
+
+/// marker in response extensions that body is already throttled
+#[derive(Clone, Copy, Debug)]
+pub struct Throttled;
+
+/// response whose body is sent at the rate of bytes per second
+///
+/// response already throttled such as by rule is kept as it is
+pub fn throttled_response(
+    response: hyper::Response<BoxBody>,
+    bytes_per_second: u64,
+) -> hyper::Response<BoxBody> {
+    if response.extensions().get::<Throttled>().is_some() {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    parts.extensions.insert(Throttled);
+    hyper::Response::from_parts(
+        parts,
+        BoxBody::new(ThrottledBody::new(body, bytes_per_second)),
+    )
+}
+
+/// body which passes inner data little by little with waits
+struct ThrottledBody {
+    inner: BoxBody,
+    bytes_per_second: u64,
+    pending: Bytes,
+    wait: Option<Pin<Box<Sleep>>>,
+}
+
+impl ThrottledBody {
+    fn new(inner: BoxBody, bytes_per_second: u64) -> Self {
+        Self {
+            inner,
+            bytes_per_second: bytes_per_second.max(1),
+            pending: Bytes::new(),
+            wait: None,
+        }
+    }
+
+    fn chunk_size(&self) -> usize {
+        (self.bytes_per_second / CHUNKS_PER_SECOND).max(1) as usize
+    }
+
+    /// time to send chunk at the rate
+    fn wait_duration(&self, chunk_len: usize) -> Duration {
+        Duration::from_secs_f64(chunk_len as f64 / self.bytes_per_second as f64)
+    }
+}
+
+impl Body for ThrottledBody {
+    type Data = Bytes;
+    type Error = io::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.get_mut();
+
+        loop {
+            if let Some(wait) = this.wait.as_mut() {
+                ready!(wait.as_mut().poll(cx));
+                this.wait = None;
+            }
+
+            if !this.pending.is_empty() {
+                let chunk_len = this.chunk_size().min(this.pending.len());
+                let chunk = this.pending.split_to(chunk_len);
+                this.wait = Some(Box::pin(tokio::time::sleep(this.wait_duration(chunk_len))));
+                return Poll::Ready(Some(Ok(Frame::data(chunk))));
+            }
+
+            match ready!(Pin::new(&mut this.inner).poll_frame(cx)) {
+                Some(Ok(frame)) => match frame.into_data() {
+                    Ok(data) => this.pending = data,
+                    Err(frame) => return Poll::Ready(Some(Ok(frame))),
+                },
+                x => return Poll::Ready(x),
+            }
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.pending.is_empty() && self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        let mut ret = self.inner.size_hint();
+        let pending_len = self.pending.len() as u64;
+        ret.set_lower(ret.lower() + pending_len);
+        if let Some(upper) = ret.upper() {
+            ret.set_upper(upper + pending_len);
+        }
+        ret
+    }
+}
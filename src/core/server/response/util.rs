@@ -2,6 +2,8 @@ use serde_json::{Map, Value};
 
 use std::path::Path;
 
+use crate::core::util::json::jsonpath::JsonPath;
+
 /// file extension string from file path
 pub fn file_extension(file_path: &str) -> Option<String> {
     match Path::new(file_path)
@@ -27,8 +29,14 @@ pub fn text_file_content_type(ext: impl AsRef<str>) -> String {
 }
 
 /// json value with jsonpath as key
+///
+/// jsonpath is expected to consist of member names such as `data.records` or `$.data['records.v1']`
 pub fn json_value_with_jsonpath_key(jsonpath_key: &str, value: Value) -> Value {
-    let mut keys: Vec<&str> = jsonpath_key.split('.').collect();
+    let jsonpath = JsonPath::parse(jsonpath_key).ok();
+    let mut keys: Vec<&str> = match jsonpath.as_ref().and_then(|x| x.member_names()) {
+        Some(x) => x,
+        None => jsonpath_key.split('.').collect(),
+    };
     keys.reverse();
 
     let mut ret = value;
@@ -42,6 +50,9 @@ pub fn json_value_with_jsonpath_key(jsonpath_key: &str, value: Value) -> Value {
     ret
 }
 
+/// content-type of unknown binary
+pub const BINARY_DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+
 /// content-type from file ext
 pub fn binary_content_type(file_path: &str) -> String {
     let content_type = match file_extension(file_path).unwrap_or_default().as_str() {
@@ -76,8 +87,34 @@ pub fn binary_content_type(file_path: &str) -> String {
         // - archive
         "zip" => "application/zip",
         // - (else)
-        _ => "application/octet-stream",
+        _ => BINARY_DEFAULT_CONTENT_TYPE,
     };
 
     content_type.to_owned()
 }
+
+/// content-type of file sent as it is. text file keeps its text type even when streamed
+pub fn file_content_type(file_path: &str) -> String {
+    match file_extension(file_path).unwrap_or_default().as_str() {
+        ext @ ("html" | "css" | "js" | "txt") => text_file_content_type(ext),
+        _ => binary_content_type(file_path),
+    }
+}
+
+/// content-type of gzip pre-compressed file such as `data.json.gz` from its inner file ext
+///
+/// none when file is not gzip
+pub fn precompressed_content_type(file_path: &str) -> Option<String> {
+    if file_extension(file_path).as_deref() != Some("gz") {
+        return None;
+    }
+
+    let inner_file_path = Path::new(file_path).with_extension("");
+    let inner_file_path = inner_file_path.to_str().unwrap_or_default();
+    let ret = match file_extension(inner_file_path).unwrap_or_default().as_str() {
+        "json" => "application/json".to_owned(),
+        "csv" => "text/csv; charset=utf-8".to_owned(),
+        _ => file_content_type(inner_file_path),
+    };
+    Some(ret)
+}
@@ -0,0 +1,77 @@
+use hyper::{
+    header::{
+        CONNECTION, CONTENT_LENGTH, SEC_WEBSOCKET_ACCEPT, SEC_WEBSOCKET_KEY, SEC_WEBSOCKET_VERSION,
+        UPGRADE,
+    },
+    HeaderMap, StatusCode,
+};
+use tokio_tungstenite::tungstenite::handshake::derive_accept_key;
+
+#[cfg(test)]
+mod tests;
+
+use super::status_code_response::status_code_response_with_message;
+use crate::core::server::{response_handler::ResponseHandler, types::BoxBody};
+
+/// websocket protocol version supported
+const WEBSOCKET_VERSION: &str = "13";
+
+/// check if request asks to upgrade to websocket
+pub fn is_websocket_upgrade(request_headers: &HeaderMap) -> bool {
+    let has_token = |key, token: &str| {
+        request_headers.get_all(key).iter().any(|x| {
+            x.to_str()
+                .unwrap_or_default()
+                .split(',')
+                .any(|x| x.trim().eq_ignore_ascii_case(token))
+        })
+    };
+
+    has_token(CONNECTION, "upgrade")
+        && has_token(UPGRADE, "websocket")
+        && request_headers.contains_key(SEC_WEBSOCKET_KEY)
+}
+
+/// `101 Switching Protocols` accepting websocket handshake
+///
+/// `426 Upgrade Required` when request is not websocket handshake
+pub fn websocket_handshake_response(
+    request_headers: &HeaderMap,
+) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
+    let key = match request_headers
+        .get(SEC_WEBSOCKET_KEY)
+        .filter(|_| is_websocket_upgrade(request_headers))
+    {
+        Some(x) => x,
+        None => {
+            return status_code_response_with_message(
+                &StatusCode::UPGRADE_REQUIRED,
+                "websocket handshake is required",
+                request_headers,
+            )
+        }
+    };
+
+    let version = request_headers
+        .get(SEC_WEBSOCKET_VERSION)
+        .and_then(|x| x.to_str().ok());
+    if version != Some(WEBSOCKET_VERSION) {
+        return ResponseHandler::default()
+            .with_status(&StatusCode::UPGRADE_REQUIRED)
+            .with_header(SEC_WEBSOCKET_VERSION.as_str(), Some(WEBSOCKET_VERSION))
+            .into_response(request_headers);
+    }
+
+    let mut response = ResponseHandler::default()
+        .with_status(&StatusCode::SWITCHING_PROTOCOLS)
+        .with_header(CONNECTION.as_str(), Some("upgrade"))
+        .with_header(UPGRADE.as_str(), Some("websocket"))
+        .with_header(
+            SEC_WEBSOCKET_ACCEPT.as_str(),
+            Some(derive_accept_key(key.as_bytes())),
+        )
+        .into_response(request_headers)?;
+    // no body is allowed in informational response
+    response.headers_mut().remove(CONTENT_LENGTH);
+    Ok(response)
+}
@@ -0,0 +1,55 @@
+use hyper::header::HeaderValue;
+
+use super::*;
+
+/// handshake headers with the sample key in RFC 6455
+fn handshake_headers() -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(CONNECTION, HeaderValue::from_static("keep-alive, Upgrade"));
+    headers.insert(UPGRADE, HeaderValue::from_static("websocket"));
+    headers.insert(
+        SEC_WEBSOCKET_KEY,
+        HeaderValue::from_static("dGhlIHNhbXBsZSBub25jZQ=="),
+    );
+    headers.insert(SEC_WEBSOCKET_VERSION, HeaderValue::from_static("13"));
+    headers
+}
+
+#[test]
+fn upgrade_detected() {
+    assert!(is_websocket_upgrade(&handshake_headers()));
+
+    let mut headers = handshake_headers();
+    headers.remove(SEC_WEBSOCKET_KEY);
+    assert!(!is_websocket_upgrade(&headers));
+
+    let mut headers = handshake_headers();
+    headers.insert(CONNECTION, HeaderValue::from_static("keep-alive"));
+    assert!(!is_websocket_upgrade(&headers));
+
+    assert!(!is_websocket_upgrade(&HeaderMap::new()));
+}
+
+#[test]
+fn handshake_accepted() {
+    let response = websocket_handshake_response(&handshake_headers()).unwrap();
+
+    assert_eq!(response.status(), StatusCode::SWITCHING_PROTOCOLS);
+    assert_eq!(
+        response.headers().get(SEC_WEBSOCKET_ACCEPT).unwrap(),
+        "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+    );
+    assert!(!response.headers().contains_key(CONTENT_LENGTH));
+}
+
+#[test]
+fn handshake_rejected() {
+    let response = websocket_handshake_response(&HeaderMap::new()).unwrap();
+    assert_eq!(response.status(), StatusCode::UPGRADE_REQUIRED);
+
+    let mut headers = handshake_headers();
+    headers.insert(SEC_WEBSOCKET_VERSION, HeaderValue::from_static("8"));
+    let response = websocket_handshake_response(&headers).unwrap();
+    assert_eq!(response.status(), StatusCode::UPGRADE_REQUIRED);
+    assert_eq!(response.headers().get(SEC_WEBSOCKET_VERSION).unwrap(), "13");
+}
@@ -3,7 +3,7 @@ use hyper::{
     body::{Body, Bytes},
     header::{
         HeaderName, HeaderValue, ACCESS_CONTROL_ALLOW_CREDENTIALS, ACCESS_CONTROL_ALLOW_ORIGIN,
-        CONTENT_LENGTH, ORIGIN, VARY,
+        CACHE_CONTROL, CONTENT_LENGTH, CONTENT_TYPE, ORIGIN, VARY,
     },
     http::response::Builder,
     HeaderMap, StatusCode,
@@ -12,15 +12,18 @@ use hyper::{
 use std::{collections::HashMap, str::FromStr};
 
 use super::{
-    constant::DEFAULT_RESPONSE_HEADERS, response::error_response::internal_server_error_response,
+    constant::DEFAULT_RESPONSE_HEADERS,
+    response::{
+        cache_response::DefaultCacheControl, error_response::internal_server_error_response,
+    },
 };
 use crate::core::server::types::BoxBody;
 
-#[derive(Clone)]
 pub enum BodyKind {
     Empty,
     Text(String),
     Binary(Vec<u8>),
+    Stream(BoxBody),
 }
 
 impl Default for BodyKind {
@@ -45,13 +48,20 @@ impl ResponseHandler {
     ) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
         // - body + content-length
         let response = match self.body_kind {
-            BodyKind::Text(s) => self
-                .response_builder
-                .body(Full::new(Bytes::from(s.to_owned())).boxed()),
-            BodyKind::Binary(b) => self
+            BodyKind::Text(s) => self.response_builder.body(
+                Full::new(Bytes::from(s.to_owned()))
+                    .map_err(|never| match never {})
+                    .boxed(),
+            ),
+            BodyKind::Binary(b) => self.response_builder.body(
+                Full::new(Bytes::from(b))
+                    .map_err(|never| match never {})
+                    .boxed(),
+            ),
+            BodyKind::Stream(body) => self.response_builder.body(body),
+            BodyKind::Empty => self
                 .response_builder
-                .body(Full::new(Bytes::from(b)).boxed()),
-            BodyKind::Empty => self.response_builder.body(Empty::new().boxed()),
+                .body(Empty::new().map_err(|never| match never {}).boxed()),
         };
 
         let mut response = match response {
@@ -71,12 +81,14 @@ impl ResponseHandler {
             StatusCode::OK
         };
 
-        // - content-length
-        let content_length = response.body().size_hint().exact().unwrap_or_default();
+        // - content-length unless body is streamed without end such as server-sent events
+        let content_length = response.body().size_hint().exact();
 
         let headers = response.headers_mut();
 
-        headers.insert(CONTENT_LENGTH, HeaderValue::from(content_length));
+        if let Some(content_length) = content_length {
+            headers.insert(CONTENT_LENGTH, HeaderValue::from(content_length));
+        }
 
         // - the other default headers
         for (header_key, header_value) in default_response_headers(request_headers).iter() {
@@ -84,6 +96,14 @@ impl ResponseHandler {
         }
 
         // - additional custom headers passed from caller
+        let has_custom_cache_control = self
+            .headers
+            .keys()
+            .any(|x| x.eq_ignore_ascii_case(CACHE_CONTROL.as_str()));
+        if !has_custom_cache_control {
+            response.extensions_mut().insert(DefaultCacheControl);
+        }
+        let headers = response.headers_mut();
         for (header_key, header_value) in self.headers {
             let _ = match HeaderName::from_str(header_key.as_str()) {
                 Ok(header_key) => {
@@ -177,6 +197,33 @@ impl ResponseHandler {
 
         self
     }
+
+    /// treat response as streamed body. sent chunked when size is unknown
+    ///
+    /// content-type is kept when already set as custom header
+    pub fn with_stream_body(
+        mut self,
+        body: BoxBody,
+        content_type: Option<impl Into<String>>,
+    ) -> Self {
+        let has_custom_content_type = self
+            .headers
+            .keys()
+            .any(|x| x.eq_ignore_ascii_case(CONTENT_TYPE.as_str()));
+        if !has_custom_content_type {
+            let content_type = if let Some(content_type) = content_type {
+                content_type.into()
+            } else {
+                "application/octet-stream".to_owned()
+            };
+            self.headers
+                .insert("content-type".into(), Some(content_type));
+        }
+
+        self.body_kind = BodyKind::Stream(body);
+
+        self
+    }
 }
 
 /// default response headers key-value pairs
@@ -14,7 +14,7 @@ use crate::core::{
 use default_respond::DefaultRespond;
 use guard::Guard;
 use prefix::Prefix;
-use rule::{respond::Respond, Rule};
+use rule::{respond::Respond, Captures, Rule};
 
 #[derive(Clone, Deserialize, Debug)]
 pub struct RuleSet {
@@ -96,13 +96,13 @@ impl RuleSet {
         ret
     }
 
-    /// find rule matching request and return its respond content
+    /// find rule matching request and return its index and respond content with named captures
     pub fn find_matched(
         &self,
         parsed_request: &ParsedRequest,
         strategy: Option<&Strategy>,
         rule_set_idx: usize,
-    ) -> Option<Respond> {
+    ) -> Option<(usize, Respond, Captures)> {
         let _ = match self.prefix.as_ref() {
             Some(prefix) if prefix.url_path_prefix.is_some() => {
                 if !parsed_request
@@ -120,7 +120,13 @@ impl RuleSet {
             if is_match {
                 // todo: last match in the future ?
                 match strategy {
-                    Some(&Strategy::FirstMatch) | None => return Some(rule.respond.to_owned()),
+                    Some(&Strategy::FirstMatch) | None => {
+                        return Some((
+                            rule_idx,
+                            rule.respond.to_owned(),
+                            rule.when.captures(parsed_request, rule_idx, rule_set_idx),
+                        ))
+                    }
                 }
             }
         }
@@ -1,13 +1,25 @@
 use serde::Deserialize;
 
+use super::rule::respond::delay::Delay;
+
+/// respond settings applied to rules without their own
 #[derive(Clone, Deserialize, Debug)]
 pub struct DefaultRespond {
-    pub delay_response_milliseconds: Option<u16>,
+    pub delay_response_milliseconds: Option<u64>,
+    pub delay: Option<Delay>,
 }
 
 impl DefaultRespond {
     /// validate
     pub fn validate(&self) -> bool {
+        if self.delay_response_milliseconds.is_some() && self.delay.is_some() {
+            log::error!("cannot set both delay_response_milliseconds and delay in default");
+            return false;
+        }
+        if let Some(Err(err)) = self.delay.as_ref().map(|x| x.validate()) {
+            log::error!("{} (default)", err);
+            return false;
+        }
         true
     }
 }
@@ -21,6 +33,9 @@ impl std::fmt::Display for DefaultRespond {
                 self.delay_response_milliseconds.as_ref().unwrap()
             );
         }
+        if let Some(delay) = self.delay.as_ref() {
+            let _ = write!(f, "[delay] {}", delay);
+        }
         Ok(())
     }
 }
@@ -1,7 +1,8 @@
 use console::style;
-use hyper::StatusCode;
 use serde::Deserialize;
 
+use std::collections::HashMap;
+
 pub mod respond;
 mod util;
 pub mod when;
@@ -10,11 +11,16 @@ use super::RuleSet;
 use respond::Respond;
 use util::url_path_with_prefix;
 use when::{
-    request::url_path::{UrlPath, UrlPathConfig},
+    request::{
+        url_path::{UrlPath, UrlPathConfig},
+        Request,
+    },
     When,
 };
 
 type ConditionKey = String;
+/// named capture groups from matched conditions: name -> matched value
+pub type Captures = HashMap<String, String>;
 
 #[derive(Clone, Deserialize, Debug)]
 pub struct Rule {
@@ -31,42 +37,21 @@ impl Rule {
     ) -> Self {
         let mut ret = self.to_owned();
 
-        // - url_path_with_prefix
-        let url_path = match ret.when.request.url_path_config.as_ref() {
-            Some(url_path_config) => match url_path_config {
-                UrlPathConfig::Simple(s) => Some(UrlPath {
-                    value: s.clone(),
-                    value_with_prefix: url_path_with_prefix(s.as_str(), rule_set.prefix.as_ref()),
-                    op: None,
-                }),
-
-                UrlPathConfig::Detailed(url_path) => Some(UrlPath {
-                    value: url_path.value.clone(),
-                    value_with_prefix: url_path_with_prefix(
-                        url_path.value.as_str(),
-                        rule_set.prefix.as_ref(),
-                    ),
-                    op: url_path.op.clone(),
-                }),
-            },
-            None => None,
-        };
-        ret.when.request.url_path = url_path;
+        // - request conditions in when tree
+        for request in ret.when.requests_mut() {
+            compute_request_derived_fields(request, rule_set, rule_idx, rule_set_idx);
+        }
 
         // - status_code
-        if let Some(status) = ret.respond.status {
-            let status_code = Some(
-                StatusCode::from_u16(status).expect(
-                    format!(
-                        "failed to get status code from status {} (rule #{} in rule set #{})",
-                        status,
-                        rule_idx + 1,
-                        rule_set_idx + 1
-                    )
-                    .as_str(),
-                ),
-            );
-            ret.respond.status_code = status_code;
+        ret.respond.compute_status_code(rule_idx, rule_set_idx);
+
+        // - regex and jsonpath in websocket replies
+        ret.respond
+            .compile_websocket_conditions(rule_idx, rule_set_idx);
+
+        // - delay inherited from rule set default
+        if let Some(default) = rule_set.default.as_ref() {
+            ret.respond.inherit_default_delay(default);
         }
 
         ret
@@ -78,6 +63,65 @@ impl Rule {
     }
 }
 
+/// url_path with prefix, path template and regex in request condition
+fn compute_request_derived_fields(
+    request: &mut Request,
+    rule_set: &RuleSet,
+    rule_idx: usize,
+    rule_set_idx: usize,
+) {
+    // - url_path_with_prefix
+    let url_path = match request.url_path_config.as_ref() {
+        Some(url_path_config) => match url_path_config {
+            UrlPathConfig::Simple(s) => Some(UrlPath {
+                value: s.clone(),
+                value_with_prefix: url_path_with_prefix(s.as_str(), rule_set.prefix.as_ref()),
+                op: None,
+                regex: None,
+                path_template: None,
+            }),
+
+            UrlPathConfig::Detailed(url_path) => Some(UrlPath {
+                value: url_path.value.clone(),
+                value_with_prefix: url_path_with_prefix(
+                    url_path.value.as_str(),
+                    rule_set.prefix.as_ref(),
+                ),
+                op: url_path.op.clone(),
+                regex: None,
+                path_template: None,
+            }),
+        },
+        None => None,
+    };
+    request.url_path = url_path;
+
+    // - url_path path template
+    if let Some(url_path) = request.url_path.as_mut() {
+        url_path.compute_path_template();
+    }
+
+    // - regex
+    if let Err(err) = request.compile_regex() {
+        panic!(
+            "failed to compile regex (rule #{} in rule set #{}) ({})",
+            rule_idx + 1,
+            rule_set_idx + 1,
+            err
+        );
+    }
+
+    // - jsonpath
+    if let Err(err) = request.parse_jsonpath() {
+        panic!(
+            "failed to parse jsonpath (rule #{} in rule set #{}) ({})",
+            rule_idx + 1,
+            rule_set_idx + 1,
+            err
+        );
+    }
+}
+
 impl std::fmt::Display for Rule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let _ = write!(f, "- ");
@@ -1,51 +1,154 @@
 use hyper::StatusCode;
 use serde::Deserialize;
-use util::full_file_path;
+use serde_json::Value;
+use util::{fill_captures, fill_path_captures, full_file_path, has_placeholder};
 
 use std::{collections::HashMap, path::Path};
 
+pub mod delay;
+pub mod one_of;
+pub mod sequence;
+pub mod shared_state;
 mod util;
+pub mod websocket;
 
+use super::{super::default_respond::DefaultRespond, Captures};
 use crate::core::{
     server::{
         parsed_request::ParsedRequest,
         response::{
-            error_response::internal_server_error_response,
+            compressed_response::{compressed_response, CompressionHandled},
+            error_response::{internal_server_error_response, not_found_response},
+            fault_response::{fault_response, Fault},
             file_response::FileResponse,
+            json_response::json_response,
+            sse_response::{sse_response, Sse},
             status_code_response::{status_code_response, status_code_response_with_message},
+            template::{self, TemplateContext},
             text_response::text_response,
+            throttled_response::throttled_response,
         },
         types::BoxBody,
     },
-    util::http::delay_response,
+    util::{http::delay_response, json::jsonpath::JsonPath},
 };
+use delay::Delay;
+use one_of::WeightedRespond;
+use sequence::SequenceEnd;
+use shared_state::{RespondSharedState, RuleKey};
+use websocket::WebSocket;
 
 #[derive(Clone, Deserialize, Debug)]
 pub struct Respond {
     pub file_path: Option<String>,
     pub csv_records_key: Option<String>,
+    /// stream csv rows as ndjson instead of records object
+    pub csv_as_ndjson: Option<bool>,
+    /// wait between ndjson records streamed from file
+    pub stream_interval_milliseconds: Option<u64>,
     pub text: Option<String>,
+    /// inline json body written as toml table or array
+    pub json: Option<Value>,
     pub status: Option<u16>,
     #[serde(skip)]
     pub status_code: Option<StatusCode>,
     pub headers: Option<HashMap<String, Option<String>>>,
-    pub delay_response_milliseconds: Option<u16>,
+    pub delay_response_milliseconds: Option<u64>,
+    /// delay picked from range or distribution
+    pub delay: Option<Delay>,
+    /// render text and text / json file content with request data
+    pub template: Option<bool>,
+    /// responds returned in order on successive calls
+    pub sequence: Option<Vec<Respond>>,
+    pub sequence_end: Option<SequenceEnd>,
+    /// responds picked randomly in proportion to weights
+    pub one_of: Option<Vec<WeightedRespond>>,
+    /// seed for reproducible picks of one_of
+    pub seed: Option<u64>,
+    /// network failure injected into response
+    pub fault: Option<Fault>,
+    /// send body at the rate instead of at once
+    pub throttle_bytes_per_second: Option<u64>,
+    /// compress body by accept-encoding. prior to service setting
+    pub compression: Option<bool>,
+    /// server-sent events stream
+    pub sse: Option<Sse>,
+    /// websocket session upgraded from request
+    pub websocket: Option<WebSocket>,
 }
 
 impl Respond {
     /// generate response
+    ///
+    /// `{name}` placeholders in file_path and text are replaced with named captures
     pub async fn response(
         &self,
         dir_prefix: &str,
         parsed_request: &ParsedRequest,
+        captures: &Captures,
     ) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
-        if let Some(delay_response_milliseconds) = self.delay_response_milliseconds {
-            delay_response(delay_response_milliseconds).await;
+        if let Some(delay_milliseconds) = self.delay_milliseconds() {
+            delay_response(delay_milliseconds).await;
         }
 
+        // handshake response has no body to post-process
+        if let Some(websocket) = self.websocket.as_ref() {
+            return websocket.response(parsed_request);
+        }
+
+        let mut response = self
+            .content_response(dir_prefix, parsed_request, captures)
+            .await?;
+        match self.compression {
+            Some(true) => {
+                response =
+                    compressed_response(response, &parsed_request.component_parts.headers).await;
+            }
+            Some(false) => {
+                response.extensions_mut().insert(CompressionHandled);
+            }
+            None => (),
+        }
+        if let Some(fault) = self.fault {
+            response = fault_response(response, fault).await;
+        }
+        if let Some(bytes_per_second) = self.throttle_bytes_per_second {
+            response = throttled_response(response, bytes_per_second);
+        }
+        Ok(response)
+    }
+
+    /// response with content of file_path, text, json or sse, or status only
+    async fn content_response(
+        &self,
+        dir_prefix: &str,
+        parsed_request: &ParsedRequest,
+        captures: &Captures,
+    ) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
         if let Some(file_path) = self.file_path.as_ref() {
-            let full_file_path = full_file_path(file_path.as_str(), dir_prefix);
+            let filled_file_path = match fill_path_captures(file_path.as_str(), captures) {
+                Some(x) => x,
+                None => {
+                    log::warn!(
+                        "captured value is not a file name: {} (prefix = {})",
+                        file_path,
+                        dir_prefix
+                    );
+                    return not_found_response(&parsed_request.component_parts.headers);
+                }
+            };
+            let full_file_path = full_file_path(filled_file_path.as_str(), dir_prefix);
             if full_file_path.is_none() {
+                // file path built with captures may point to missing resource
+                if has_placeholder(file_path.as_str()) {
+                    log::warn!(
+                        "{} (prefix = {}) is missing",
+                        filled_file_path.as_str(),
+                        dir_prefix
+                    );
+                    return not_found_response(&parsed_request.component_parts.headers);
+                }
+
                 log::error!(
                     "{} (prefix = {}) is missing",
                     self.file_path.clone().unwrap_or_default().as_str(),
@@ -56,15 +159,29 @@ impl Respond {
                     &parsed_request.component_parts.headers,
                 );
             }
-            FileResponse::new_with_csv_records_jsonpath(
+            let mut file_response = FileResponse::new_with_csv_records_jsonpath(
                 full_file_path.unwrap().as_str(),
                 self.headers.as_ref(),
                 self.csv_records_key.clone(),
                 &parsed_request.component_parts.headers,
-            )
-            .file_content_response()
-            .await
+            );
+            file_response = file_response
+                .with_status(self.status_code)
+                .with_stream_interval(self.stream_interval_milliseconds)
+                .with_csv_as_ndjson(self.csv_as_ndjson.unwrap_or(false));
+            if self.is_template() {
+                file_response =
+                    file_response.with_template(TemplateContext::new(parsed_request, captures));
+            }
+            file_response.file_content_response().await
         } else if let Some(text) = self.text.as_ref() {
+            // captures are filled after rendered so that request data is never rendered as template
+            let text = if self.is_template() {
+                TemplateContext::new(parsed_request, captures).render_text(text.as_str())
+            } else {
+                text.to_owned()
+            };
+            let text = fill_captures(text.as_str(), captures);
             if let Some(status_code) = self.status_code.as_ref() {
                 status_code_response_with_message(
                     status_code,
@@ -79,8 +196,34 @@ impl Respond {
                     &parsed_request.component_parts.headers,
                 )
             }
+        } else if let Some(json) = self.json.as_ref() {
+            let json = if self.is_template() {
+                TemplateContext::new(parsed_request, captures).render_json(json.to_owned())
+            } else {
+                json.to_owned()
+            };
+            json_response(
+                &json,
+                self.status_code.as_ref(),
+                self.headers.as_ref(),
+                &parsed_request.component_parts.headers,
+            )
+        } else if let Some(sse) = self.sse.as_ref() {
+            let full_file_path = sse
+                .file_path
+                .as_ref()
+                .and_then(|x| full_file_path(x.as_str(), dir_prefix));
+            sse_response(
+                sse.load_events(full_file_path.as_deref()).await,
+                sse.end.unwrap_or_default(),
+                self.status_code.as_ref(),
+                self.headers.as_ref(),
+                &parsed_request.component_parts.headers,
+            )
         } else if let Some(status_code) = self.status_code.as_ref() {
             status_code_response(status_code, &parsed_request.component_parts.headers)
+        } else if self.fault.is_some() {
+            status_code_response(&StatusCode::OK, &parsed_request.component_parts.headers)
         } else {
             internal_server_error_response(
                 "invalid respond def",
@@ -89,40 +232,291 @@ impl Respond {
         }
     }
 
+    /// respond to return on this call from sequence or one_of. otherwise itself
+    pub fn resolve(&self, shared_state: &RespondSharedState, key: RuleKey) -> &Respond {
+        if let Some(sequence) = self.sequence.as_ref().filter(|x| !x.is_empty()) {
+            let idx = shared_state.sequence_counters.next_index(
+                key,
+                sequence.len(),
+                self.sequence_end.unwrap_or_default(),
+            );
+            return &sequence[idx];
+        }
+
+        if let Some(one_of) = self.one_of.as_ref().filter(|x| !x.is_empty()) {
+            let weights = one_of.iter().map(|x| x.weight()).collect::<Vec<u32>>();
+            let idx = shared_state
+                .one_of_rngs
+                .pick_index(key, weights.as_slice(), self.seed);
+            return &one_of[idx].respond;
+        }
+
+        self
+    }
+
+    /// delay milliseconds of this response, fixed or picked from range or distribution
+    pub fn delay_milliseconds(&self) -> Option<u64> {
+        match self.delay.as_ref() {
+            Some(delay) => Some(delay.milliseconds()),
+            None => self.delay_response_milliseconds,
+        }
+    }
+
+    /// apply rule set default delay to responds without their own delay
+    pub fn inherit_default_delay(&mut self, default: &DefaultRespond) {
+        if self.is_group() {
+            for x in self.sequence.iter_mut().flatten() {
+                x.inherit_default_delay(default);
+            }
+            for x in self.one_of.iter_mut().flatten() {
+                x.respond.inherit_default_delay(default);
+            }
+            return;
+        }
+
+        if self.delay_response_milliseconds.is_none() && self.delay.is_none() {
+            self.delay_response_milliseconds = default.delay_response_milliseconds;
+            self.delay = default.delay.clone();
+        }
+    }
+
+    /// status code from status including those in sequence and one_of
+    pub fn compute_status_code(&mut self, rule_idx: usize, rule_set_idx: usize) {
+        if let Some(status) = self.status {
+            let status_code = StatusCode::from_u16(status).unwrap_or_else(|err| {
+                panic!(
+                    "failed to get status code from status {} (rule #{} in rule set #{}) ({})",
+                    status,
+                    rule_idx + 1,
+                    rule_set_idx + 1,
+                    err
+                )
+            });
+            self.status_code = Some(status_code);
+        }
+
+        for x in self.sequence.iter_mut().flatten() {
+            x.compute_status_code(rule_idx, rule_set_idx);
+        }
+        for x in self.one_of.iter_mut().flatten() {
+            x.respond.compute_status_code(rule_idx, rule_set_idx);
+        }
+    }
+
+    /// regex and jsonpath in websocket reply conditions including those in sequence and one_of
+    pub fn compile_websocket_conditions(&mut self, rule_idx: usize, rule_set_idx: usize) {
+        if let Some(websocket) = self.websocket.as_mut() {
+            if let Err(err) = websocket.compile_regex() {
+                panic!(
+                    "failed to compile regex in websocket (rule #{} in rule set #{}) ({})",
+                    rule_idx + 1,
+                    rule_set_idx + 1,
+                    err
+                );
+            }
+            if let Err(err) = websocket.parse_jsonpath() {
+                panic!(
+                    "failed to parse jsonpath in websocket (rule #{} in rule set #{}) ({})",
+                    rule_idx + 1,
+                    rule_set_idx + 1,
+                    err
+                );
+            }
+        }
+
+        for x in self.sequence.iter_mut().flatten() {
+            x.compile_websocket_conditions(rule_idx, rule_set_idx);
+        }
+        for x in self.one_of.iter_mut().flatten() {
+            x.respond
+                .compile_websocket_conditions(rule_idx, rule_set_idx);
+        }
+    }
+
+    /// check if template mode is enabled
+    pub fn is_template(&self) -> bool {
+        self.template.unwrap_or(false)
+    }
+
     /// validate
     pub fn validate(&self, dir_prefix: &str, rule_idx: usize, rule_set_idx: usize) -> bool {
-        let all_missing_of_file_path_text_status =
-            self.file_path.is_none() && self.text.is_none() && self.status.is_none();
-        if all_missing_of_file_path_text_status {
+        if self.sequence.is_some() && self.one_of.is_some() {
             log::error!(
-                "require at least either of file_path, text or status (rule #{} in rule set #{})",
+                "cannot set both sequence and one_of (rule #{} in rule set #{})",
+                rule_idx + 1,
+                rule_set_idx + 1
+            );
+            return false;
+        }
+        if let Some(sequence) = self.sequence.as_ref() {
+            return self.sequence_validate(sequence, dir_prefix, rule_idx, rule_set_idx);
+        }
+        if let Some(one_of) = self.one_of.as_ref() {
+            return self.one_of_validate(one_of, dir_prefix, rule_idx, rule_set_idx);
+        }
+        if self.seed.is_some() {
+            log::error!(
+                "seed requires one_of (rule #{} in rule set #{})",
+                rule_idx + 1,
+                rule_set_idx + 1
+            );
+            return false;
+        }
+        if self.delay_response_milliseconds.is_some() && self.delay.is_some() {
+            log::error!(
+                "cannot set both delay_response_milliseconds and delay (rule #{} in rule set #{})",
+                rule_idx + 1,
+                rule_set_idx + 1
+            );
+            return false;
+        }
+        if let Some(Err(err)) = self.delay.as_ref().map(|x| x.validate()) {
+            log::error!(
+                "{} (rule #{} in rule set #{})",
+                err,
                 rule_idx + 1,
                 rule_set_idx + 1
             );
             return false;
         }
 
-        let duplicate_file_path_text = self.file_path.is_some() && self.text.is_some();
-        if duplicate_file_path_text {
+        if self.throttle_bytes_per_second == Some(0) {
             log::error!(
-                "cannot set at both file_path and text (rule #{} in rule set #{})",
+                "throttle_bytes_per_second must be positive (rule #{} in rule set #{})",
                 rule_idx + 1,
                 rule_set_idx + 1
             );
             return false;
         }
 
-        let file_path_with_status = self.file_path.is_some() && self.status.is_some();
-        if file_path_with_status {
+        if self.sequence_end.is_some() {
             log::error!(
-                "status only supports text. file_path is not (rule #{} in rule set #{})",
+                "sequence_end requires sequence (rule #{} in rule set #{})",
                 rule_idx + 1,
                 rule_set_idx + 1
             );
             return false;
         }
 
+        let all_missing_of_file_path_text_json_sse_websocket_status_fault =
+            self.file_path.is_none()
+                && self.text.is_none()
+                && self.json.is_none()
+                && self.sse.is_none()
+                && self.websocket.is_none()
+                && self.status.is_none()
+                && self.fault.is_none();
+        if all_missing_of_file_path_text_json_sse_websocket_status_fault {
+            log::error!(
+                "require at least either of file_path, text, json, sse, websocket, status or fault (rule #{} in rule set #{})",
+                rule_idx + 1,
+                rule_set_idx + 1
+            );
+            return false;
+        }
+
+        let body_sources_count = [
+            self.file_path.is_some(),
+            self.text.is_some(),
+            self.json.is_some(),
+            self.sse.is_some(),
+            self.websocket.is_some(),
+        ]
+        .into_iter()
+        .filter(|x| *x)
+        .count();
+        if 1 < body_sources_count {
+            log::error!(
+                "cannot set two or more of file_path, text, json, sse and websocket (rule #{} in rule set #{})",
+                rule_idx + 1,
+                rule_set_idx + 1
+            );
+            return false;
+        }
+
+        if let Some(json) = self.json.as_ref() {
+            if !json.is_object() && !json.is_array() {
+                log::error!(
+                    "json must be table or array (rule #{} in rule set #{})",
+                    rule_idx + 1,
+                    rule_set_idx + 1
+                );
+                return false;
+            }
+        }
+
+        if self.file_path.is_none()
+            && (self.csv_as_ndjson.is_some() || self.stream_interval_milliseconds.is_some())
+        {
+            log::error!(
+                "csv_as_ndjson and stream_interval_milliseconds require file_path (rule #{} in rule set #{})",
+                rule_idx + 1,
+                rule_set_idx + 1
+            );
+            return false;
+        }
+        if self.csv_as_ndjson == Some(true) && self.csv_records_key.is_some() {
+            log::error!(
+                "cannot set both csv_as_ndjson and csv_records_key (rule #{} in rule set #{})",
+                rule_idx + 1,
+                rule_set_idx + 1
+            );
+            return false;
+        }
+
+        if let Some(sse) = self.sse.as_ref() {
+            return self.sse_validate(sse, dir_prefix, rule_idx, rule_set_idx);
+        }
+
+        if let Some(websocket) = self.websocket.as_ref() {
+            return self.websocket_validate(websocket, rule_idx, rule_set_idx);
+        }
+
+        if let Some(text) = self.text.as_ref().filter(|_| self.is_template()) {
+            if let Err(err) = template::validate(text) {
+                log::error!(
+                    "{} (rule #{} in rule set #{})",
+                    err,
+                    rule_idx + 1,
+                    rule_set_idx + 1
+                );
+                return false;
+            }
+        }
+
+        if let Some(json) = self.json.as_ref().filter(|_| self.is_template()) {
+            if let Err(err) = template::validate_json(json) {
+                log::error!(
+                    "{} (rule #{} in rule set #{})",
+                    err,
+                    rule_idx + 1,
+                    rule_set_idx + 1
+                );
+                return false;
+            }
+        }
+
+        if let Some(csv_records_key) = self.csv_records_key.as_ref() {
+            let is_member_path = JsonPath::parse(csv_records_key)
+                .ok()
+                .and_then(|x| x.member_names().map(|names| !names.is_empty()))
+                .unwrap_or(false);
+            if !is_member_path {
+                log::error!(
+                    "csv_records_key must consist of member names: `{}` (rule #{} in rule set #{})",
+                    csv_records_key,
+                    rule_idx + 1,
+                    rule_set_idx + 1
+                );
+                return false;
+            }
+        }
+
         if let Some(file_path) = self.file_path.as_ref() {
+            // file path with placeholders is resolved on each request
+            if has_placeholder(file_path.as_str()) {
+                return true;
+            }
             file_path_validate(file_path.as_str(), dir_prefix, rule_idx, rule_set_idx)
         } else {
             true
@@ -130,6 +524,198 @@ impl Respond {
     }
 }
 
+impl Respond {
+    /// validate sse and its events
+    fn sse_validate(
+        &self,
+        sse: &Sse,
+        dir_prefix: &str,
+        rule_idx: usize,
+        rule_set_idx: usize,
+    ) -> bool {
+        if self.fault.is_some() || self.is_template() || self.csv_records_key.is_some() {
+            log::error!(
+                "sse cannot be combined with fault, template or csv_records_key (rule #{} in rule set #{})",
+                rule_idx + 1,
+                rule_set_idx + 1
+            );
+            return false;
+        }
+
+        if let Some(file_path) = sse.file_path.as_ref() {
+            if !file_path_validate(file_path.as_str(), dir_prefix, rule_idx, rule_set_idx) {
+                return false;
+            }
+        }
+
+        let full_file_path = sse
+            .file_path
+            .as_ref()
+            .and_then(|x| full_file_path(x.as_str(), dir_prefix));
+        match sse
+            .read_events(full_file_path.as_deref())
+            .and_then(|events| sse.validate(events.as_slice()))
+        {
+            Ok(_) => true,
+            Err(err) => {
+                log::error!(
+                    "{} (rule #{} in rule set #{})",
+                    err,
+                    rule_idx + 1,
+                    rule_set_idx + 1
+                );
+                false
+            }
+        }
+    }
+}
+
+impl Respond {
+    /// validate websocket and its messages
+    fn websocket_validate(
+        &self,
+        websocket: &WebSocket,
+        rule_idx: usize,
+        rule_set_idx: usize,
+    ) -> bool {
+        if self.status.is_some()
+            || self.headers.is_some()
+            || self.fault.is_some()
+            || self.throttle_bytes_per_second.is_some()
+            || self.compression.is_some()
+            || self.is_template()
+            || self.csv_records_key.is_some()
+        {
+            log::error!(
+                "websocket cannot be combined with status, headers, fault, throttle_bytes_per_second, compression, template or csv_records_key (rule #{} in rule set #{})",
+                rule_idx + 1,
+                rule_set_idx + 1
+            );
+            return false;
+        }
+
+        if let Err(err) = websocket.validate() {
+            log::error!(
+                "{} (rule #{} in rule set #{})",
+                err,
+                rule_idx + 1,
+                rule_set_idx + 1
+            );
+            return false;
+        }
+        true
+    }
+}
+
+impl Respond {
+    /// validate sequence and each respond in it
+    fn sequence_validate(
+        &self,
+        sequence: &[Respond],
+        dir_prefix: &str,
+        rule_idx: usize,
+        rule_set_idx: usize,
+    ) -> bool {
+        if sequence.is_empty() {
+            log::error!(
+                "sequence requires at least one respond (rule #{} in rule set #{})",
+                rule_idx + 1,
+                rule_set_idx + 1
+            );
+            return false;
+        }
+
+        if self.has_other_fields() || self.one_of.is_some() || self.seed.is_some() {
+            log::error!(
+                "sequence cannot be combined with other respond fields except sequence_end. set them in each respond in sequence (rule #{} in rule set #{})",
+                rule_idx + 1,
+                rule_set_idx + 1
+            );
+            return false;
+        }
+
+        if sequence.iter().any(|x| x.is_group()) {
+            log::error!(
+                "sequence cannot be nested or include one_of (rule #{} in rule set #{})",
+                rule_idx + 1,
+                rule_set_idx + 1
+            );
+            return false;
+        }
+
+        sequence
+            .iter()
+            .all(|x| x.validate(dir_prefix, rule_idx, rule_set_idx))
+    }
+}
+
+impl Respond {
+    /// validate one_of and each respond in it
+    fn one_of_validate(
+        &self,
+        one_of: &[WeightedRespond],
+        dir_prefix: &str,
+        rule_idx: usize,
+        rule_set_idx: usize,
+    ) -> bool {
+        if one_of.iter().all(|x| x.weight() == 0) {
+            log::error!(
+                "one_of requires at least one respond with positive weight (rule #{} in rule set #{})",
+                rule_idx + 1,
+                rule_set_idx + 1
+            );
+            return false;
+        }
+
+        if self.has_other_fields() || self.sequence_end.is_some() {
+            log::error!(
+                "one_of cannot be combined with other respond fields except seed. set them in each respond in one_of (rule #{} in rule set #{})",
+                rule_idx + 1,
+                rule_set_idx + 1
+            );
+            return false;
+        }
+
+        if one_of.iter().any(|x| x.respond.is_group()) {
+            log::error!(
+                "one_of cannot be nested or include sequence (rule #{} in rule set #{})",
+                rule_idx + 1,
+                rule_set_idx + 1
+            );
+            return false;
+        }
+
+        one_of
+            .iter()
+            .all(|x| x.respond.validate(dir_prefix, rule_idx, rule_set_idx))
+    }
+
+    /// check if respond groups others by sequence or one_of
+    fn is_group(&self) -> bool {
+        self.sequence.is_some() || self.one_of.is_some()
+    }
+
+    /// check if respond has fields other than groups
+    fn has_other_fields(&self) -> bool {
+        self.file_path.is_some()
+            || self.csv_records_key.is_some()
+            || self.csv_as_ndjson.is_some()
+            || self.stream_interval_milliseconds.is_some()
+            || self.text.is_some()
+            || self.json.is_some()
+            || self.status.is_some()
+            || self.headers.is_some()
+            || self.delay_response_milliseconds.is_some()
+            || self.delay.is_some()
+            || self.template.is_some()
+            || self.fault.is_some()
+            || self.throttle_bytes_per_second.is_some()
+            || self.compression.is_some()
+            || self.sse.is_some()
+            || self.websocket.is_some()
+    }
+}
+
 impl std::fmt::Display for Respond {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Some(status_code) = self.status_code {
@@ -138,9 +724,72 @@ impl std::fmt::Display for Respond {
         if let Some(text) = self.text.as_ref() {
             let _ = writeln!(f, "text = `{}` ", text);
         }
+        if let Some(json) = self.json.as_ref() {
+            let _ = writeln!(f, "json = `{}` ", json);
+        }
         if let Some(file_path) = self.file_path.as_ref() {
             let _ = writeln!(f, "file_path = `{}` ", file_path);
         }
+        if self.csv_as_ndjson == Some(true) {
+            let _ = writeln!(f, "(csv as ndjson) ");
+        }
+        if let Some(stream_interval_milliseconds) = self.stream_interval_milliseconds {
+            let _ = writeln!(f, "stream interval = {} ms ", stream_interval_milliseconds);
+        }
+        if let Some(sse) = self.sse.as_ref() {
+            let _ = writeln!(f, "sse = {} ", sse);
+        }
+        if let Some(websocket) = self.websocket.as_ref() {
+            let _ = writeln!(f, "websocket = {} ", websocket);
+        }
+        if let Some(fault) = self.fault {
+            let _ = writeln!(f, "fault = {} ", fault);
+        }
+        if let Some(bytes_per_second) = self.throttle_bytes_per_second {
+            let _ = writeln!(f, "throttle = {} bytes/s ", bytes_per_second);
+        }
+        if let Some(compression) = self.compression {
+            let _ = writeln!(
+                f,
+                "compression = {} ",
+                if compression { "Yes" } else { "No" }
+            );
+        }
+        if let Some(delay) = self.delay.as_ref() {
+            let _ = writeln!(f, "delay = {} ", delay);
+        } else if let Some(delay_response_milliseconds) = self.delay_response_milliseconds {
+            let _ = writeln!(f, "delay = {} ms ", delay_response_milliseconds);
+        }
+        if self.is_template() {
+            let _ = writeln!(f, "(template) ");
+        }
+        if let Some(sequence) = self.sequence.as_ref() {
+            let _ = writeln!(
+                f,
+                "sequence ({}, {}) ",
+                sequence.len(),
+                self.sequence_end.unwrap_or_default()
+            );
+            for (idx, x) in sequence.iter().enumerate() {
+                let _ = write!(f, "  #{}: {}", idx + 1, x.to_string().replace('\n', " "));
+                let _ = writeln!(f);
+            }
+        }
+        if let Some(one_of) = self.one_of.as_ref() {
+            let _ = match self.seed {
+                Some(seed) => writeln!(f, "one_of ({}, seed = {}) ", one_of.len(), seed),
+                None => writeln!(f, "one_of ({}) ", one_of.len()),
+            };
+            for x in one_of.iter() {
+                let _ = write!(
+                    f,
+                    "  weight {}: {}",
+                    x.weight(),
+                    x.respond.to_string().replace('\n', " ")
+                );
+                let _ = writeln!(f);
+            }
+        }
 
         Ok(())
     }
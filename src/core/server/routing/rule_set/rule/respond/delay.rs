@@ -0,0 +1,175 @@
+use rand::Rng;
+use serde::Deserialize;
+
+#[cfg(test)]
+mod tests;
+
+/// z-score of 99th percentile in standard normal distribution
+const Z_SCORE_P99: f64 = 2.326_347_874;
+
+/// parameter name and value
+type Param = (&'static str, Option<u64>);
+
+/// response delay picked from range or distribution. values are in milliseconds
+///
+/// - `uniform` (default): between `min` and `max`
+/// - `normal`: around `mean` with `std_dev`
+/// - `log_normal`: long-tailed with median `p50` and 99th percentile `p99`
+///
+/// `min` and `max` clamp delays of `normal` and `log_normal` when set
+#[derive(Clone, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Delay {
+    pub distribution: Option<DelayDistribution>,
+    pub min: Option<u64>,
+    pub max: Option<u64>,
+    pub mean: Option<u64>,
+    pub std_dev: Option<u64>,
+    pub p50: Option<u64>,
+    pub p99: Option<u64>,
+}
+
+#[derive(Clone, Copy, Default, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DelayDistribution {
+    #[default]
+    Uniform,
+    Normal,
+    LogNormal,
+}
+
+impl Delay {
+    /// delay milliseconds picked randomly
+    pub fn milliseconds(&self) -> u64 {
+        let mut rng = rand::rng();
+        let ret = match self.distribution.unwrap_or_default() {
+            DelayDistribution::Uniform => {
+                let min = self.min.unwrap_or_default();
+                let max = self.max.unwrap_or(min).max(min);
+                return rng.random_range(min..=max);
+            }
+            DelayDistribution::Normal => {
+                let mean = self.mean.unwrap_or_default() as f64;
+                let std_dev = self.std_dev.unwrap_or_default() as f64;
+                mean + std_dev * standard_normal(&mut rng)
+            }
+            DelayDistribution::LogNormal => {
+                let (mu, sigma) =
+                    log_normal_params(self.p50.unwrap_or_default(), self.p99.unwrap_or_default());
+                (mu + sigma * standard_normal(&mut rng)).exp()
+            }
+        };
+        self.clamp(ret)
+    }
+
+    /// validate required parameters of distribution
+    pub fn validate(&self) -> Result<(), String> {
+        let distribution = self.distribution.unwrap_or_default();
+        let (required, unexpected): (&[Param], &[Param]) = match distribution {
+            DelayDistribution::Uniform => (
+                &[("min", self.min), ("max", self.max)],
+                &[
+                    ("mean", self.mean),
+                    ("std_dev", self.std_dev),
+                    ("p50", self.p50),
+                    ("p99", self.p99),
+                ],
+            ),
+            DelayDistribution::Normal => (
+                &[("mean", self.mean), ("std_dev", self.std_dev)],
+                &[("p50", self.p50), ("p99", self.p99)],
+            ),
+            DelayDistribution::LogNormal => (
+                &[("p50", self.p50), ("p99", self.p99)],
+                &[("mean", self.mean), ("std_dev", self.std_dev)],
+            ),
+        };
+
+        if let Some((name, _)) = required.iter().find(|(_, x)| x.is_none()) {
+            return Err(format!(
+                "delay of {} distribution requires {}",
+                distribution, name
+            ));
+        }
+        if let Some((name, _)) = unexpected.iter().find(|(_, x)| x.is_some()) {
+            return Err(format!(
+                "delay of {} distribution does not use {}",
+                distribution, name
+            ));
+        }
+
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            if max < min {
+                return Err(format!("delay max {} is less than min {}", max, min));
+            }
+        }
+        if let (Some(p50), Some(p99)) = (self.p50, self.p99) {
+            if p50 == 0 || p99 < p50 {
+                return Err(format!(
+                    "delay p50 must be positive and p99 must not be less than p50 (p50 = {}, p99 = {})",
+                    p50, p99
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// round to milliseconds within min and max
+    fn clamp(&self, milliseconds: f64) -> u64 {
+        let ret = milliseconds.max(0.0).round() as u64;
+        let ret = match self.min {
+            Some(min) => ret.max(min),
+            None => ret,
+        };
+        match self.max {
+            Some(max) => ret.min(max),
+            None => ret,
+        }
+    }
+}
+
+/// mu and sigma of log-normal distribution from its median and 99th percentile
+fn log_normal_params(p50: u64, p99: u64) -> (f64, f64) {
+    let mu = (p50.max(1) as f64).ln();
+    let sigma = ((p99.max(p50).max(1) as f64).ln() - mu) / Z_SCORE_P99;
+    (mu, sigma)
+}
+
+/// random value of standard normal distribution (box-muller transform)
+fn standard_normal(rng: &mut impl Rng) -> f64 {
+    let u1 = 1.0 - rng.random::<f64>();
+    let u2 = rng.random::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+impl std::fmt::Display for DelayDistribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Uniform => write!(f, "uniform"),
+            Self::Normal => write!(f, "normal"),
+            Self::LogNormal => write!(f, "log_normal"),
+        }
+    }
+}
+
+impl std::fmt::Display for Delay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params = [
+            ("min", self.min),
+            ("max", self.max),
+            ("mean", self.mean),
+            ("std_dev", self.std_dev),
+            ("p50", self.p50),
+            ("p99", self.p99),
+        ]
+        .into_iter()
+        .filter_map(|(name, x)| x.map(|x| format!("{} = {}", name, x)))
+        .collect::<Vec<String>>();
+        write!(
+            f,
+            "{} ({}) ms",
+            self.distribution.unwrap_or_default(),
+            params.join(", ")
+        )
+    }
+}
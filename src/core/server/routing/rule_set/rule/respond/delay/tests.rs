@@ -0,0 +1,85 @@
+use super::*;
+
+fn delay(distribution: DelayDistribution) -> Delay {
+    Delay {
+        distribution: Some(distribution),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn uniform_within_range() {
+    let delay = Delay {
+        min: Some(100),
+        max: Some(200),
+        ..delay(DelayDistribution::Uniform)
+    };
+    assert!(delay.validate().is_ok());
+    assert!((0..100).all(|_| (100..=200).contains(&delay.milliseconds())));
+}
+
+#[test]
+fn normal_clamped() {
+    let delay = Delay {
+        mean: Some(100),
+        std_dev: Some(1_000),
+        min: Some(50),
+        max: Some(150),
+        ..delay(DelayDistribution::Normal)
+    };
+    assert!(delay.validate().is_ok());
+    assert!((0..100).all(|_| (50..=150).contains(&delay.milliseconds())));
+}
+
+#[test]
+fn log_normal_percentiles() {
+    let (mu, sigma) = log_normal_params(100, 1_000);
+    assert!((mu.exp() - 100.0).abs() < 1e-9);
+    assert!(((mu + sigma * Z_SCORE_P99).exp() - 1_000.0).abs() < 1e-6);
+
+    let delay = Delay {
+        p50: Some(100),
+        p99: Some(1_000),
+        ..delay(DelayDistribution::LogNormal)
+    };
+    assert!(delay.validate().is_ok());
+    let mut samples = (0..2_000)
+        .map(|_| delay.milliseconds())
+        .collect::<Vec<u64>>();
+    samples.sort();
+    let median = samples[samples.len() / 2];
+    assert!((70..=140).contains(&median));
+}
+
+#[test]
+fn validate_parameters() {
+    assert!(delay(DelayDistribution::Uniform).validate().is_err());
+    assert!(Delay {
+        min: Some(200),
+        max: Some(100),
+        ..delay(DelayDistribution::Uniform)
+    }
+    .validate()
+    .is_err());
+    assert!(Delay {
+        mean: Some(100),
+        ..delay(DelayDistribution::Normal)
+    }
+    .validate()
+    .is_err());
+    assert!(Delay {
+        p50: Some(100),
+        p99: Some(1_000),
+        mean: Some(100),
+        ..delay(DelayDistribution::LogNormal)
+    }
+    .validate()
+    .is_err());
+    assert!(Delay {
+        p50: Some(0),
+        p99: Some(1_000),
+        ..delay(DelayDistribution::LogNormal)
+    }
+    .validate()
+    .is_err());
+}
@@ -0,0 +1,70 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::Deserialize;
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use super::{shared_state::RuleKey, Respond};
+
+#[cfg(test)]
+mod tests;
+
+pub const DEFAULT_WEIGHT: u32 = 1;
+
+/// respond picked randomly in proportion to weight
+#[derive(Clone, Deserialize, Debug)]
+pub struct WeightedRespond {
+    pub weight: Option<u32>,
+    #[serde(flatten)]
+    pub respond: Respond,
+}
+
+impl WeightedRespond {
+    pub fn weight(&self) -> u32 {
+        self.weight.unwrap_or(DEFAULT_WEIGHT)
+    }
+}
+
+/// random number generators of seeded `one_of` per rule
+#[derive(Clone, Debug, Default)]
+pub struct OneOfRngs {
+    rngs: Arc<Mutex<HashMap<RuleKey, StdRng>>>,
+}
+
+impl OneOfRngs {
+    /// index picked in proportion to weights
+    ///
+    /// with seed, picks are reproducible in the order of calls to the rule
+    pub fn pick_index(&self, key: RuleKey, weights: &[u32], seed: Option<u64>) -> usize {
+        let total = weights.iter().map(|x| u64::from(*x)).sum::<u64>();
+        if total == 0 {
+            return 0;
+        }
+
+        let n = match seed {
+            Some(seed) => {
+                let mut rngs = self.rngs.lock().unwrap_or_else(|err| err.into_inner());
+                rngs.entry(key)
+                    .or_insert_with(|| StdRng::seed_from_u64(seed))
+                    .random_range(0..total)
+            }
+            None => rand::rng().random_range(0..total),
+        };
+
+        weighted_index(weights, n)
+    }
+}
+
+/// index of weight range which `n` (less than total of weights) falls into
+fn weighted_index(weights: &[u32], n: u64) -> usize {
+    let mut upper = 0;
+    for (idx, weight) in weights.iter().enumerate() {
+        upper += u64::from(*weight);
+        if n < upper {
+            return idx;
+        }
+    }
+    weights.len().saturating_sub(1)
+}
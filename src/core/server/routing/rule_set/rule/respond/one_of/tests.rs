@@ -0,0 +1,26 @@
+use super::*;
+
+#[test]
+fn weighted_ranges() {
+    let weights = [9, 0, 1];
+    assert_eq!(weighted_index(&weights, 0), 0);
+    assert_eq!(weighted_index(&weights, 8), 0);
+    assert_eq!(weighted_index(&weights, 9), 2);
+}
+
+#[test]
+fn seeded_picks_are_reproducible() {
+    let weights = [1, 1, 1];
+    let picks = |rngs: &OneOfRngs| {
+        (0..20)
+            .map(|_| rngs.pick_index((0, 0), &weights, Some(42)))
+            .collect::<Vec<usize>>()
+    };
+    assert_eq!(picks(&OneOfRngs::default()), picks(&OneOfRngs::default()));
+}
+
+#[test]
+fn zero_weight_is_never_picked() {
+    let rngs = OneOfRngs::default();
+    assert!((0..100).all(|_| rngs.pick_index((0, 0), &[0, 1], None) == 1));
+}
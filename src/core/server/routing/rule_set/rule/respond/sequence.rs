@@ -0,0 +1,61 @@
+use serde::Deserialize;
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use super::shared_state::RuleKey;
+
+#[cfg(test)]
+mod tests;
+
+/// behavior after the last respond in sequence is returned
+#[derive(Clone, Copy, Default, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SequenceEnd {
+    /// start again from the first respond
+    Loop,
+    /// keep returning the last respond
+    #[default]
+    StickToLast,
+}
+
+impl std::fmt::Display for SequenceEnd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Loop => write!(f, "loop"),
+            Self::StickToLast => write!(f, "stick_to_last"),
+        }
+    }
+}
+
+/// call counts of sequence responds per rule
+///
+/// shared among requests and connections so that it is kept through clones of app state
+#[derive(Clone, Debug, Default)]
+pub struct SequenceCounters {
+    counts: Arc<Mutex<HashMap<RuleKey, usize>>>,
+}
+
+impl SequenceCounters {
+    /// index of respond to return in sequence of `len`, counting up the call
+    pub fn next_index(&self, key: RuleKey, len: usize, sequence_end: SequenceEnd) -> usize {
+        let mut counts = self.counts.lock().unwrap_or_else(|err| err.into_inner());
+        let count = counts.entry(key).or_default();
+        let ret = match sequence_end {
+            SequenceEnd::Loop => *count % len,
+            SequenceEnd::StickToLast => (*count).min(len - 1),
+        };
+        *count = count.saturating_add(1);
+        ret
+    }
+
+    /// reset all counts so that every sequence starts again from the first respond
+    pub fn reset(&self) {
+        self.counts
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clear();
+    }
+}
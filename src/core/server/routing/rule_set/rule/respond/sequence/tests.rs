@@ -0,0 +1,31 @@
+use super::*;
+
+#[test]
+fn stick_to_last() {
+    let counters = SequenceCounters::default();
+    let indexes = (0..4)
+        .map(|_| counters.next_index((0, 0), 3, SequenceEnd::StickToLast))
+        .collect::<Vec<usize>>();
+    assert_eq!(indexes, vec![0, 1, 2, 2]);
+}
+
+#[test]
+fn loop_sequence() {
+    let counters = SequenceCounters::default();
+    let indexes = (0..5)
+        .map(|_| counters.next_index((0, 0), 2, SequenceEnd::Loop))
+        .collect::<Vec<usize>>();
+    assert_eq!(indexes, vec![0, 1, 0, 1, 0]);
+}
+
+#[test]
+fn counts_per_rule_and_reset() {
+    let counters = SequenceCounters::default();
+    let shared = counters.clone();
+    assert_eq!(counters.next_index((0, 0), 3, SequenceEnd::Loop), 0);
+    assert_eq!(shared.next_index((0, 0), 3, SequenceEnd::Loop), 1);
+    assert_eq!(counters.next_index((0, 1), 3, SequenceEnd::Loop), 0);
+
+    shared.reset();
+    assert_eq!(counters.next_index((0, 0), 3, SequenceEnd::Loop), 0);
+}
@@ -0,0 +1,13 @@
+use super::{one_of::OneOfRngs, sequence::SequenceCounters};
+
+/// rule set index and rule index
+pub type RuleKey = (usize, usize);
+
+/// respond state shared among requests and connections
+///
+/// kept through clones of app state so that it persists while the server is running
+#[derive(Clone, Debug, Default)]
+pub struct RespondSharedState {
+    pub sequence_counters: SequenceCounters,
+    pub one_of_rngs: OneOfRngs,
+}
@@ -1,5 +1,10 @@
 use std::path::Path;
 
+use crate::core::server::routing::rule_set::rule::Captures;
+
+#[cfg(test)]
+mod tests;
+
 /// full file path by joining path prefix to file path
 pub fn full_file_path(path: &str, dir_prefix: &str) -> Option<String> {
     let p = if !dir_prefix.is_empty() {
@@ -24,3 +29,58 @@ pub fn full_file_path(path: &str, dir_prefix: &str) -> Option<String> {
         }
     }
 }
+
+/// replace `{name}` placeholders with named capture values
+///
+/// placeholders are filled from left to right at once, so values are never filled again
+pub fn fill_captures(s: &str, captures: &Captures) -> String {
+    fill_placeholders(s, captures, |_| true).unwrap_or_default()
+}
+
+/// file path whose `{name}` placeholders are filled with named capture values
+///
+/// none when a value would escape the directory: empty, dot segment or with path separator
+pub fn fill_path_captures(file_path: &str, captures: &Captures) -> Option<String> {
+    fill_placeholders(file_path, captures, |value| {
+        !matches!(value, "" | "." | "..") && !value.contains(['/', '\\'])
+    })
+}
+
+/// fill placeholders with values accepted. none when any value is rejected
+fn fill_placeholders(
+    s: &str,
+    captures: &Captures,
+    accepts: impl Fn(&str) -> bool,
+) -> Option<String> {
+    let mut ret = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(end) = rest.find('}') {
+        // the last `{` before `}` so that braces around placeholder such as json are kept
+        let start = match rest[..end].rfind('{') {
+            Some(x) => x,
+            None => {
+                ret.push_str(&rest[..=end]);
+                rest = &rest[end + 1..];
+                continue;
+            }
+        };
+        ret.push_str(&rest[..start]);
+        match captures.get(&rest[start + 1..end]) {
+            Some(value) if accepts(value.as_str()) => ret.push_str(value),
+            Some(_) => return None,
+            // unknown name is kept as it is
+            None => ret.push_str(&rest[start..=end]),
+        }
+        rest = &rest[end + 1..];
+    }
+    ret.push_str(rest);
+    Some(ret)
+}
+
+/// check if `{...}` placeholder is included
+pub fn has_placeholder(s: &str) -> bool {
+    match s.find('{') {
+        Some(start) => s[start..].contains('}'),
+        None => false,
+    }
+}
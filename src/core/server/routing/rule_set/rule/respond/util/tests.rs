@@ -0,0 +1,48 @@
+use super::*;
+
+fn captures(pairs: &[(&str, &str)]) -> Captures {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn fill_captures_at_once() {
+    let captures = captures(&[("id", "{name}"), ("name", "alice")]);
+    assert_eq!(
+        fill_captures("{id} is {name} ({unknown})", &captures),
+        "{name} is alice ({unknown})"
+    );
+}
+
+#[test]
+fn fill_captures_in_json() {
+    let captures = captures(&[("id", "1")]);
+    assert_eq!(
+        fill_captures(r#"{"id":"{id}","items":[{"name":"{name}"}]}"#, &captures),
+        r#"{"id":"1","items":[{"name":"{name}"}]}"#
+    );
+}
+
+#[test]
+fn fill_path_captures_in_file_name() {
+    let captures = captures(&[("id", "1"), ("ext", "json")]);
+    assert_eq!(
+        fill_path_captures("users/{id}.{ext}", &captures).as_deref(),
+        Some("users/1.json")
+    );
+}
+
+#[test]
+fn fill_path_captures_rejects_escape() {
+    for value in ["", ".", "..", "../secret", "/etc/passwd", "a\\b"] {
+        let captures = captures(&[("id", value)]);
+        assert_eq!(
+            fill_path_captures("users/{id}", &captures),
+            None,
+            "{}",
+            value
+        );
+    }
+}
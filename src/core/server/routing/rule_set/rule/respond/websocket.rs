@@ -0,0 +1,261 @@
+use futures_util::{SinkExt, StreamExt};
+use hyper::{body::Bytes, upgrade::OnUpgrade};
+use hyper_util::rt::TokioIo;
+use serde::Deserialize;
+use serde_json::Value;
+use tokio_tungstenite::{
+    tungstenite::{protocol::Role, Message},
+    WebSocketStream,
+};
+
+use std::time::Duration;
+
+use crate::core::server::{
+    parsed_request::ParsedRequest,
+    response::{
+        error_response::internal_server_error_response,
+        websocket_response::{is_websocket_upgrade, websocket_handshake_response},
+    },
+    routing::rule_set::rule::when::request::body::Body,
+    types::BoxBody,
+};
+
+/// websocket session scripted by rule
+#[derive(Clone, Deserialize, Debug)]
+pub struct WebSocket {
+    /// messages sent in order just after connected
+    pub on_connect: Option<Vec<WebSocketMessage>>,
+    /// replies to incoming messages. the first matched is used
+    pub replies: Option<Vec<WebSocketReply>>,
+}
+
+/// message sent to client
+#[derive(Clone, Deserialize, Debug)]
+pub struct WebSocketMessage {
+    pub text: Option<String>,
+    /// text frame of table or array written as json
+    pub json: Option<Value>,
+    /// wait before this message is sent
+    pub delay_milliseconds: Option<u64>,
+}
+
+/// reply to incoming message matching conditions
+#[derive(Clone, Deserialize, Debug)]
+pub struct WebSocketReply {
+    /// conditions on message payload. any message matches when missing
+    pub when: Option<WebSocketWhen>,
+    pub send: Option<Vec<WebSocketMessage>>,
+    /// close connection after messages are sent
+    pub close: Option<bool>,
+}
+
+/// conditions written as `when.request.body`
+#[derive(Clone, Deserialize, Debug)]
+pub struct WebSocketWhen {
+    pub body: Body,
+}
+
+impl WebSocket {
+    /// accept handshake and run session on upgraded connection in background
+    pub fn response(
+        &self,
+        parsed_request: &ParsedRequest,
+    ) -> Result<hyper::Response<BoxBody>, hyper::http::Error> {
+        let request_headers = &parsed_request.component_parts.headers;
+        if !is_websocket_upgrade(request_headers) {
+            return websocket_handshake_response(request_headers);
+        }
+
+        let on_upgrade = match parsed_request
+            .component_parts
+            .extensions
+            .get::<OnUpgrade>()
+            .cloned()
+        {
+            Some(x) => x,
+            None => {
+                return internal_server_error_response(
+                    "connection is not upgradable to websocket",
+                    request_headers,
+                )
+            }
+        };
+
+        let response = websocket_handshake_response(request_headers)?;
+        if response.status() != hyper::StatusCode::SWITCHING_PROTOCOLS {
+            return Ok(response);
+        }
+
+        let websocket = self.clone();
+        // message is matched as request body keeping the other request data
+        let message_request = parsed_request.with_body(Bytes::new());
+        tokio::spawn(async move {
+            match on_upgrade.await {
+                Ok(upgraded) => {
+                    let stream = WebSocketStream::from_raw_socket(
+                        TokioIo::new(upgraded),
+                        Role::Server,
+                        None,
+                    )
+                    .await;
+                    websocket.run(stream, message_request).await;
+                }
+                Err(err) => log::error!("failed to upgrade to websocket: {}", err),
+            }
+        });
+
+        Ok(response)
+    }
+
+    /// send scripted messages and reply to incoming ones until closed
+    async fn run(
+        &self,
+        mut stream: WebSocketStream<TokioIo<hyper::upgrade::Upgraded>>,
+        message_request: ParsedRequest,
+    ) {
+        let url_path = message_request.url_path.as_str();
+        log::info!("websocket connected: {}", url_path);
+
+        for message in self.on_connect.iter().flatten() {
+            if let Err(err) = message.send(&mut stream).await {
+                log::warn!("websocket failed to send message: {} ({})", url_path, err);
+                return;
+            }
+        }
+
+        while let Some(received) = stream.next().await {
+            let payload = match received {
+                Ok(Message::Text(x)) => Bytes::from(x.as_str().to_owned()),
+                Ok(Message::Binary(x)) => x,
+                Ok(Message::Close(_)) => break,
+                // ping is answered by protocol
+                Ok(_) => continue,
+                Err(err) => {
+                    log::warn!("websocket connection error: {} ({})", url_path, err);
+                    break;
+                }
+            };
+
+            let request = message_request.with_body(payload);
+            let reply = match self.replies.iter().flatten().find(|x| x.is_match(&request)) {
+                Some(x) => x,
+                None => {
+                    log::info!("websocket message has no reply: {}", url_path);
+                    continue;
+                }
+            };
+
+            for message in reply.send.iter().flatten() {
+                if let Err(err) = message.send(&mut stream).await {
+                    log::warn!("websocket failed to send message: {} ({})", url_path, err);
+                    return;
+                }
+            }
+            if reply.close.unwrap_or(false) {
+                let _ = stream.close(None).await;
+                break;
+            }
+        }
+
+        log::info!("websocket disconnected: {}", url_path);
+    }
+
+    /// compile regex in advance in each reply condition
+    pub fn compile_regex(&mut self) -> Result<(), regex::Error> {
+        for reply in self.replies.iter_mut().flatten() {
+            if let Some(when) = reply.when.as_mut() {
+                when.body.compile_regex()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// parse jsonpath in advance in each reply condition
+    pub fn parse_jsonpath(&mut self) -> Result<(), String> {
+        for reply in self.replies.iter_mut().flatten() {
+            if let Some(when) = reply.when.as_mut() {
+                when.body.parse_jsonpath()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// validate
+    pub fn validate(&self) -> Result<(), String> {
+        let messages = self.on_connect.iter().flatten().chain(
+            self.replies
+                .iter()
+                .flatten()
+                .flat_map(|x| x.send.iter().flatten()),
+        );
+        for message in messages {
+            message.validate()?;
+        }
+
+        for (idx, reply) in self.replies.iter().flatten().enumerate() {
+            if reply.when.as_ref().is_some_and(|x| !x.body.validate()) {
+                return Err(format!(
+                    "websocket reply #{} has invalid when.body",
+                    idx + 1
+                ));
+            }
+            if reply.send.is_none() && reply.close.is_none() {
+                return Err(format!(
+                    "websocket reply #{} requires either of send or close",
+                    idx + 1
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for WebSocket {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} on connect, {} replies",
+            self.on_connect.as_ref().map_or(0, |x| x.len()),
+            self.replies.as_ref().map_or(0, |x| x.len())
+        )
+    }
+}
+
+impl WebSocketMessage {
+    /// send as text frame after delay
+    async fn send(
+        &self,
+        stream: &mut WebSocketStream<TokioIo<hyper::upgrade::Upgraded>>,
+    ) -> Result<(), tokio_tungstenite::tungstenite::Error> {
+        if let Some(delay_milliseconds) = self.delay_milliseconds {
+            tokio::time::sleep(Duration::from_millis(delay_milliseconds)).await;
+        }
+
+        let text = match (self.text.as_ref(), self.json.as_ref()) {
+            (Some(text), _) => text.to_owned(),
+            (None, Some(json)) => json.to_string(),
+            (None, None) => return Ok(()),
+        };
+        stream.send(Message::text(text)).await
+    }
+
+    /// validate
+    fn validate(&self) -> Result<(), String> {
+        match (self.text.as_ref(), self.json.as_ref()) {
+            (Some(_), Some(_)) => Err("websocket message cannot set both text and json".to_owned()),
+            (None, None) => Err("websocket message requires either of text or json".to_owned()),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl WebSocketReply {
+    /// check if incoming message matches conditions
+    fn is_match(&self, request: &ParsedRequest) -> bool {
+        match self.when.as_ref() {
+            Some(when) => when.body.is_match(request),
+            None => true,
+        }
+    }
+}
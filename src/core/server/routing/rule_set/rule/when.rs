@@ -1,14 +1,23 @@
+use console::style;
 use serde::Deserialize;
 
 mod condition_statement;
 pub mod request;
 
+use super::Captures;
 use crate::core::server::parsed_request::ParsedRequest;
-use request::Request;
+use request::{util::fmt_condition_connector, Request};
 
+/// matching conditions
+///
+/// `request` and groups of `any`, `all` and `not` are combined with AND logic.
+/// groups hold nested `When` so that they can be combined recursively
 #[derive(Clone, Deserialize, Debug)]
 pub struct When {
-    pub request: Request,
+    pub request: Option<Request>,
+    pub any: Option<Vec<When>>,
+    pub all: Option<Vec<When>>,
+    pub not: Option<Box<When>>,
 }
 
 impl When {
@@ -19,19 +28,167 @@ impl When {
         rule_idx: usize,
         rule_set_idx: usize,
     ) -> bool {
-        self.request
-            .is_match(parsed_request, rule_idx, rule_set_idx)
+        let request_is_match = self.request.is_none()
+            || self
+                .request
+                .as_ref()
+                .unwrap()
+                .is_match(parsed_request, rule_idx, rule_set_idx);
+
+        let any_is_match = self.any.is_none()
+            || self
+                .any
+                .as_ref()
+                .unwrap()
+                .iter()
+                .any(|x| x.is_match(parsed_request, rule_idx, rule_set_idx));
+
+        let all_is_match = self.all.is_none()
+            || self
+                .all
+                .as_ref()
+                .unwrap()
+                .iter()
+                .all(|x| x.is_match(parsed_request, rule_idx, rule_set_idx));
+
+        let not_is_match = self.not.is_none()
+            || !self
+                .not
+                .as_ref()
+                .unwrap()
+                .is_match(parsed_request, rule_idx, rule_set_idx);
+
+        request_is_match && any_is_match && all_is_match && not_is_match
+    }
+
+    /// named capture groups of regex conditions in matched request
+    ///
+    /// in `any`, captures come from the first matched condition. `not` has none
+    pub fn captures(
+        &self,
+        parsed_request: &ParsedRequest,
+        rule_idx: usize,
+        rule_set_idx: usize,
+    ) -> Captures {
+        let mut ret = Captures::new();
+        self.collect_captures(parsed_request, rule_idx, rule_set_idx, &mut ret);
+        ret
+    }
+
+    fn collect_captures(
+        &self,
+        parsed_request: &ParsedRequest,
+        rule_idx: usize,
+        rule_set_idx: usize,
+        captures: &mut Captures,
+    ) {
+        if let Some(request) = self.request.as_ref() {
+            request.collect_captures(parsed_request, captures);
+        }
+        if let Some(any) = self.any.as_ref() {
+            if let Some(matched) = any
+                .iter()
+                .find(|x| x.is_match(parsed_request, rule_idx, rule_set_idx))
+            {
+                matched.collect_captures(parsed_request, rule_idx, rule_set_idx, captures);
+            }
+        }
+        if let Some(all) = self.all.as_ref() {
+            for x in all.iter() {
+                x.collect_captures(parsed_request, rule_idx, rule_set_idx, captures);
+            }
+        }
+    }
+
+    /// all request conditions in tree
+    pub fn requests_mut(&mut self) -> Vec<&mut Request> {
+        let mut ret = vec![];
+        if let Some(request) = self.request.as_mut() {
+            ret.push(request);
+        }
+        for x in self.any.iter_mut().chain(self.all.iter_mut()).flatten() {
+            ret.extend(x.requests_mut());
+        }
+        if let Some(not) = self.not.as_mut() {
+            ret.extend(not.requests_mut());
+        }
+        ret
     }
 
     /// validate
     pub fn validate(&self, rule_idx: usize, rule_set_idx: usize) -> bool {
-        self.request.validate(rule_idx, rule_set_idx)
+        if self.request.is_none() && self.any.is_none() && self.all.is_none() && self.not.is_none()
+        {
+            log::error!(
+                "either of request, any, all or not in when is required (rule #{} in rule set #{})",
+                rule_idx + 1,
+                rule_set_idx + 1
+            );
+            return false;
+        }
+
+        let request_validate = match self.request.as_ref() {
+            Some(request) => request.validate(rule_idx, rule_set_idx),
+            None => true,
+        };
+
+        let groups_validate = [("any", self.any.as_ref()), ("all", self.all.as_ref())]
+            .into_iter()
+            .all(|(name, group)| match group {
+                Some(group) if group.is_empty() => {
+                    log::error!(
+                        "{} in when requires at least one condition (rule #{} in rule set #{})",
+                        name,
+                        rule_idx + 1,
+                        rule_set_idx + 1
+                    );
+                    false
+                }
+                Some(group) => group.iter().all(|x| x.validate(rule_idx, rule_set_idx)),
+                None => true,
+            });
+
+        let not_validate = match self.not.as_ref() {
+            Some(not) => not.validate(rule_idx, rule_set_idx),
+            None => true,
+        };
+
+        request_validate && groups_validate && not_validate
     }
 }
 
 impl std::fmt::Display for When {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let _ = write!(f, "{}", self.request);
+        let mut s: Vec<String> = vec![];
+
+        if let Some(x) = self.request.as_ref() {
+            s.push(x.to_string().trim_end().to_owned());
+        }
+        if let Some(x) = self.any.as_ref() {
+            s.push(fmt_group(
+                "any",
+                x,
+                style(" || ").dim().to_string().as_str(),
+            ));
+        }
+        if let Some(x) = self.all.as_ref() {
+            s.push(fmt_group("all", x, fmt_condition_connector().as_str()));
+        }
+        if let Some(x) = self.not.as_ref() {
+            s.push(fmt_group("not", std::slice::from_ref(x.as_ref()), ""));
+        }
+
+        let _ = write!(f, "{} ", s.join(fmt_condition_connector().as_str()));
         Ok(())
     }
 }
+
+/// group of conditions such as `any( a || b )`
+fn fmt_group(name: &str, group: &[When], connector: &str) -> String {
+    let s = group
+        .iter()
+        .map(|x| x.to_string().trim_end().to_owned())
+        .collect::<Vec<String>>()
+        .join(connector);
+    format!("{}( {} )", style(name).cyan(), s)
+}
@@ -1,15 +1,151 @@
+use regex::Regex;
 use serde::Deserialize;
+use serde_json::Value;
 
-use super::request::rule_op::RuleOp;
+use std::borrow::Cow;
+
+#[cfg(test)]
+mod tests;
+
+use super::request::{rule_op::RuleOp, util::collect_named_captures};
+use crate::core::{server::routing::rule_set::rule::Captures, util::json::json_value_str};
 
 #[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct ConditionStatement {
     pub op: Option<RuleOp>,
-    pub value: String,
+    /// required except for exists and absent
+    pub value: Option<String>,
+    /// compare ignoring case. applies to every op
+    #[serde(default)]
+    pub case_insensitive: bool,
+    /// remove leading and trailing whitespaces of request value before comparison
+    #[serde(default)]
+    pub trim: bool,
+    #[serde(skip)]
+    pub regex: Option<Regex>,
+}
+
+impl ConditionStatement {
+    /// match with condition
+    pub fn is_match(&self, text: &str) -> bool {
+        let op = self.op.clone().unwrap_or_default();
+        op.is_match_with_regex(
+            self.normalize(text, &op).as_ref(),
+            self.checker(&op).as_ref(),
+            self.regex.as_ref(),
+        )
+    }
+
+    /// request value with modifiers applied
+    ///
+    /// regex keeps case because it is compiled as case insensitive and captures should keep original text
+    fn normalize<'a>(&self, text: &'a str, op: &RuleOp) -> Cow<'a, str> {
+        let text = if self.trim { text.trim() } else { text };
+        match op {
+            RuleOp::Regex => Cow::Borrowed(text),
+            _ if self.case_insensitive => Cow::Owned(text.to_lowercase()),
+            _ => Cow::Borrowed(text),
+        }
+    }
+
+    /// condition value with modifiers applied
+    fn checker(&self, op: &RuleOp) -> Cow<'_, str> {
+        match op {
+            _ if !self.case_insensitive => Cow::Borrowed(self.value()),
+            // used only when regex is not compiled in advance
+            RuleOp::Regex => Cow::Owned(format!("(?i){}", self.value())),
+            _ => Cow::Owned(self.value().to_lowercase()),
+        }
+    }
+
+    /// condition value. empty when missing for exists and absent
+    fn value(&self) -> &str {
+        self.value.as_deref().unwrap_or_default()
+    }
+
+    /// match with value possibly missing
+    pub fn is_match_value(&self, text: Option<&str>) -> bool {
+        match (self.op.clone().unwrap_or_default(), text) {
+            (RuleOp::Exists, x) => x.is_some(),
+            (RuleOp::Absent, x) => x.is_none(),
+            (_, Some(text)) => self.is_match(text),
+            (_, None) => false,
+        }
+    }
+
+    /// match with json value possibly missing
+    ///
+    /// numeric, type and length ops see json type. the others compare value as text
+    pub fn is_match_json_value(&self, value: Option<&Value>) -> bool {
+        let value = match value {
+            Some(x) => x,
+            None => return self.is_match_value(None),
+        };
+
+        let op = self.op.clone().unwrap_or_default();
+        let normalized;
+        let value = match value {
+            Value::String(s) if self.case_insensitive || self.trim => {
+                normalized = Value::String(self.normalize(s, &op).into_owned());
+                &normalized
+            }
+            _ => value,
+        };
+        match op.is_match_json(value, self.checker(&op).as_ref()) {
+            Some(ret) => ret,
+            None => self.is_match_value(Some(json_value_str(value).as_str())),
+        }
+    }
+
+    /// validate value available for op
+    pub fn validate(&self) -> bool {
+        let op = self.op.clone().unwrap_or_default();
+        if self.value.is_none() && !op.is_existence_op() {
+            log::error!("value is required for op `{}`", op.to_string().trim());
+            return false;
+        }
+        match op.validate_checker(self.value()) {
+            Ok(_) => true,
+            Err(err) => {
+                log::error!("invalid value for op `{}`: {}", op.to_string().trim(), err);
+                false
+            }
+        }
+    }
+
+    /// compile regex in advance when op is regex
+    pub fn compile_regex(&mut self) -> Result<(), regex::Error> {
+        self.regex = self
+            .op
+            .clone()
+            .unwrap_or_default()
+            .compile_regex(self.value(), self.case_insensitive)?;
+        Ok(())
+    }
+
+    /// collect named capture groups when op is regex
+    pub fn collect_captures(&self, text: &str, captures: &mut Captures) {
+        if let Some(regex) = self.regex.as_ref() {
+            let text = if self.trim { text.trim() } else { text };
+            collect_named_captures(regex, text, captures);
+        }
+    }
 }
 
 impl std::fmt::Display for ConditionStatement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}`{}`", self.op.clone().unwrap_or_default(), self.value)
+        let op = self.op.clone().unwrap_or_default();
+        if op.is_existence_op() {
+            return write!(f, "{}", op);
+        }
+        let _ = write!(f, "{}`{}`", op, self.value());
+        if self.case_insensitive {
+            let _ = write!(f, " (case insensitive)");
+        }
+        if self.trim {
+            let _ = write!(f, " (trimmed)");
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,62 @@
+use serde_json::json;
+
+use super::*;
+
+fn statement(op: RuleOp, value: &str, case_insensitive: bool, trim: bool) -> ConditionStatement {
+    let mut ret = ConditionStatement {
+        op: Some(op),
+        value: Some(value.to_owned()),
+        case_insensitive,
+        trim,
+        regex: None,
+    };
+    ret.compile_regex().unwrap();
+    ret
+}
+
+#[test]
+fn case_insensitive() {
+    let cases = vec![
+        (RuleOp::Equal, "application/json", "application/JSON"),
+        (RuleOp::StartsWith, "bearer ", "Bearer xyz"),
+        (RuleOp::Contains, "JSON", "application/json"),
+        (RuleOp::WildCard, "BEARER *", "Bearer xyz"),
+        (RuleOp::Regex, "^bearer [a-z]+$", "Bearer XYZ"),
+    ];
+    for (op, value, text) in cases {
+        assert!(!statement(op.clone(), value, false, false).is_match(text));
+        assert!(statement(op, value, true, false).is_match(text));
+    }
+    assert!(!statement(RuleOp::NotEqual, "abc", true, false).is_match("ABC"));
+}
+
+#[test]
+fn trim() {
+    assert!(!statement(RuleOp::Equal, "abc", false, false).is_match(" abc\n"));
+    assert!(statement(RuleOp::Equal, "abc", false, true).is_match(" abc\n"));
+    assert!(statement(RuleOp::Length, "3", false, true).is_match_json_value(Some(&json!(" abc "))));
+    assert!(statement(RuleOp::Equal, "abc", true, true).is_match_json_value(Some(&json!(" ABC "))));
+}
+
+#[test]
+fn regex_captures_keep_case() {
+    let statement = statement(RuleOp::Regex, "^(?<name>[a-z]+)$", true, true);
+    let mut captures = Captures::new();
+    statement.collect_captures(" Alice ", &mut captures);
+    assert_eq!(captures.get("name").map(String::as_str), Some("Alice"));
+}
+
+#[test]
+fn value_required_except_existence() {
+    let missing = |op: &str| -> ConditionStatement {
+        toml::from_str(format!("op = \"{}\"", op).as_str()).unwrap()
+    };
+
+    assert!(!missing("equal").validate());
+    assert!(!missing("regex").validate());
+    assert!(missing("exists").validate());
+    assert!(missing("absent").validate());
+
+    let empty: ConditionStatement = toml::from_str("value = \"\"").unwrap();
+    assert!(empty.validate());
+}
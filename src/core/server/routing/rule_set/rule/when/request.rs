@@ -1,16 +1,25 @@
 use http_method::HttpMethod;
 use serde::Deserialize;
 
-mod body;
+pub mod body;
+mod client_ip;
+mod cookies;
 mod headers;
+mod host;
 mod http_method;
+mod query;
 pub mod rule_op;
 pub mod url_path;
-mod util;
+pub mod util;
 
+use super::super::Captures;
 use crate::core::server::parsed_request::ParsedRequest;
 use body::Body;
+use client_ip::ClientIp;
+use cookies::Cookies;
 use headers::Headers;
+use host::Host;
+use query::Query;
 use url_path::{UrlPath, UrlPathConfig};
 use util::fmt_condition_connector;
 
@@ -22,7 +31,11 @@ pub struct Request {
     pub url_path: Option<UrlPath>,
     #[serde(rename = "method")]
     pub http_method: Option<HttpMethod>,
+    pub query: Option<Query>,
     pub headers: Option<Headers>,
+    pub cookies: Option<Cookies>,
+    pub client_ip: Option<ClientIp>,
+    pub host: Option<Host>,
     pub body: Option<Body>,
 }
 
@@ -48,6 +61,13 @@ impl Request {
                 .unwrap()
                 .is_match(&parsed_request.component_parts.method);
 
+        let query_is_match = self.query.is_none()
+            || self
+                .query
+                .as_ref()
+                .unwrap()
+                .is_match(&parsed_request.query_params);
+
         let headers_is_match = self.headers.is_none()
             || self.headers.as_ref().unwrap().is_match(
                 &parsed_request.component_parts.headers,
@@ -55,30 +75,140 @@ impl Request {
                 rule_set_idx,
             );
 
+        let cookies_is_match = self.cookies.is_none()
+            || self
+                .cookies
+                .as_ref()
+                .unwrap()
+                .is_match(&parsed_request.cookies);
+
+        let client_ip_is_match = self.client_ip.is_none()
+            || self
+                .client_ip
+                .as_ref()
+                .unwrap()
+                .is_match(&parsed_request.client_addr);
+
+        let host_is_match = self.host.is_none()
+            || self
+                .host
+                .as_ref()
+                .unwrap()
+                .is_match(parsed_request.host.as_deref());
+
         let body_is_match =
             self.body.is_none() || self.body.as_ref().unwrap().is_match(&parsed_request);
 
-        url_path_is_match && http_method_is_match && headers_is_match && body_is_match
+        url_path_is_match
+            && http_method_is_match
+            && query_is_match
+            && headers_is_match
+            && cookies_is_match
+            && client_ip_is_match
+            && host_is_match
+            && body_is_match
+    }
+
+    /// compile regex in advance in each condition
+    pub fn compile_regex(&mut self) -> Result<(), regex::Error> {
+        if let Some(url_path) = self.url_path.as_mut() {
+            url_path.compile_regex()?;
+        }
+        if let Some(query) = self.query.as_mut() {
+            query.compile_regex()?;
+        }
+        if let Some(headers) = self.headers.as_mut() {
+            headers.compile_regex()?;
+        }
+        if let Some(cookies) = self.cookies.as_mut() {
+            cookies.compile_regex()?;
+        }
+        if let Some(host) = self.host.as_mut() {
+            host.compile_regex()?;
+        }
+        if let Some(body) = self.body.as_mut() {
+            body.compile_regex()?;
+        }
+        Ok(())
+    }
+
+    /// parse jsonpath in advance in body conditions
+    pub fn parse_jsonpath(&mut self) -> Result<(), String> {
+        match self.body.as_mut() {
+            Some(body) => body.parse_jsonpath(),
+            None => Ok(()),
+        }
+    }
+
+    /// collect named capture groups of regex conditions
+    pub fn collect_captures(&self, parsed_request: &ParsedRequest, captures: &mut Captures) {
+        if let Some(url_path) = self.url_path.as_ref() {
+            url_path.collect_captures(parsed_request.url_path.as_str(), captures);
+        }
+        if let Some(query) = self.query.as_ref() {
+            query.collect_captures(&parsed_request.query_params, captures);
+        }
+        if let Some(headers) = self.headers.as_ref() {
+            headers.collect_captures(&parsed_request.component_parts.headers, captures);
+        }
+        if let Some(cookies) = self.cookies.as_ref() {
+            cookies.collect_captures(&parsed_request.cookies, captures);
+        }
+        if let Some(host) = self.host.as_ref() {
+            host.collect_captures(parsed_request.host.as_deref(), captures);
+        }
+        if let Some(body) = self.body.as_ref() {
+            body.collect_captures(parsed_request, captures);
+        }
     }
 
     /// validate
     pub fn validate(&self, rule_idx: usize, rule_set_idx: usize) -> bool {
         if self.url_path.is_none()
             && self.http_method.is_none()
+            && self.query.is_none()
             && self.headers.is_none()
+            && self.cookies.is_none()
+            && self.client_ip.is_none()
+            && self.host.is_none()
             && self.body.is_none()
         {
-            log::error!("either of url_path, method, headers or body in when.request is required (rule #{} in rule set #{})", rule_idx + 1, rule_set_idx + 1);
+            log::error!("either of url_path, method, query, headers, cookies, client_ip, host or body in when.request is required (rule #{} in rule set #{})", rule_idx + 1, rule_set_idx + 1);
             return false;
         }
 
         let url_path_validate = match self.url_path.as_ref() {
-            Some(url_path) => url_path.validate(),
+            Some(url_path) => {
+                let ret = url_path.validate();
+                if !ret {
+                    log::error!(
+                        "something wrong in url_path (rule #{} in rule set #{})",
+                        rule_idx + 1,
+                        rule_set_idx + 1
+                    )
+                }
+                ret
+            }
             None => true,
         };
 
         let http_method_validate = true;
 
+        let query_validate = match self.query.as_ref() {
+            Some(query) => {
+                let ret = query.validate();
+                if !ret {
+                    log::error!(
+                        "something wrong in query (rule #{} in rule set #{})",
+                        rule_idx + 1,
+                        rule_set_idx + 1
+                    )
+                }
+                ret
+            }
+            None => true,
+        };
+
         let headers_validate = match self.headers.as_ref() {
             Some(headers) => {
                 let ret = headers.validate();
@@ -94,6 +224,51 @@ impl Request {
             None => true,
         };
 
+        let cookies_validate = match self.cookies.as_ref() {
+            Some(cookies) => {
+                let ret = cookies.validate();
+                if !ret {
+                    log::error!(
+                        "something wrong in cookies (rule #{} in rule set #{})",
+                        rule_idx + 1,
+                        rule_set_idx + 1
+                    )
+                }
+                ret
+            }
+            None => true,
+        };
+
+        let client_ip_validate = match self.client_ip.as_ref() {
+            Some(client_ip) => {
+                let ret = client_ip.validate();
+                if !ret {
+                    log::error!(
+                        "something wrong in client_ip (rule #{} in rule set #{})",
+                        rule_idx + 1,
+                        rule_set_idx + 1
+                    )
+                }
+                ret
+            }
+            None => true,
+        };
+
+        let host_validate = match self.host.as_ref() {
+            Some(host) => {
+                let ret = host.validate();
+                if !ret {
+                    log::error!(
+                        "something wrong in host (rule #{} in rule set #{})",
+                        rule_idx + 1,
+                        rule_set_idx + 1
+                    )
+                }
+                ret
+            }
+            None => true,
+        };
+
         let body_validate = match self.body.as_ref() {
             Some(body) => {
                 let ret = body.validate();
@@ -109,7 +284,14 @@ impl Request {
             None => true,
         };
 
-        url_path_validate && http_method_validate && headers_validate && body_validate
+        url_path_validate
+            && http_method_validate
+            && query_validate
+            && headers_validate
+            && cookies_validate
+            && client_ip_validate
+            && host_validate
+            && body_validate
     }
 }
 
@@ -123,9 +305,21 @@ impl std::fmt::Display for Request {
         if let Some(x) = self.http_method.as_ref() {
             s.push(format!("{}", x));
         }
+        if let Some(x) = self.query.as_ref() {
+            s.push(format!("{}", x));
+        }
         if let Some(x) = self.headers.as_ref() {
             s.push(format!("{}", x));
         }
+        if let Some(x) = self.cookies.as_ref() {
+            s.push(format!("{}", x));
+        }
+        if let Some(x) = self.client_ip.as_ref() {
+            s.push(format!("{}", x));
+        }
+        if let Some(x) = self.host.as_ref() {
+            s.push(format!("{}", x));
+        }
         if let Some(x) = self.body.as_ref() {
             s.push(format!("{}", x));
         }
@@ -1,73 +1,202 @@
+use roxmltree::Document;
 use serde::Deserialize;
 use serde_json::Value;
 
 use std::collections::HashMap;
 
+mod body_condition;
 mod body_kind;
 
 use super::util::fmt_condition_connector;
 use crate::core::{
     server::{
         parsed_request::ParsedRequest,
-        routing::rule_set::rule::{when::condition_statement::ConditionStatement, ConditionKey},
+        routing::rule_set::rule::{Captures, ConditionKey},
+    },
+    util::{
+        json::{json_value_str, jsonpath::JsonPath},
+        multipart::MultipartPart,
+        xml::xml_values_by_path,
     },
-    util::json::json_value_by_jsonpath,
 };
+use body_condition::BodyCondition;
 use body_kind::BodyKind;
 
 #[derive(Clone, Debug, Deserialize)]
 #[serde(transparent)]
-pub struct Body(pub HashMap<BodyKind, HashMap<ConditionKey, ConditionStatement>>);
+pub struct Body {
+    pub conditions: HashMap<BodyKind, BodyCondition>,
+    /// json condition keys parsed in advance
+    #[serde(skip)]
+    jsonpaths: HashMap<ConditionKey, JsonPath>,
+}
 
 impl Body {
     /// check if `body` in `when` matches
+    ///
+    /// when body kind has multiple values by key (such as repeated form field), any of them matching is enough
     pub fn is_match(&self, parsed_request: &ParsedRequest) -> bool {
-        // todo: support other types than json (such as form value) in the future
-        let request_body_json = match parsed_request.body_json.as_ref() {
-            Some(x) => x,
-            None => return false,
-        };
-
-        let matcher_body_json_condition = match self.0.get(&BodyKind::Json) {
-            Some(x) if !x.is_empty() => x,
-            _ => return false,
-        };
-
-        let ret = matcher_body_json_condition.iter().all(
-            |(matcher_json_condition_key, matcher_json_condition_statement)| {
-                let request_body_json_value =
-                    match json_value_by_jsonpath(request_body_json, matcher_json_condition_key) {
-                        Some(x) => match x {
-                            Value::String(s) => s.to_owned(),
-                            _ => x.to_string(),
-                        },
-                        None => return false,
-                    };
-
-                let ret = matcher_json_condition_statement
-                    .op
-                    .clone()
-                    .unwrap_or_default()
-                    .is_match(
-                        request_body_json_value.as_str(),
-                        &matcher_json_condition_statement.value,
-                    );
-                ret
-            },
-        );
+        if self.conditions.is_empty() {
+            return false;
+        }
+
+        self.conditions
+            .iter()
+            .all(|(body_kind, matcher_condition)| {
+                let request_body = match RequestBody::new(body_kind, parsed_request) {
+                    Some(x) => x,
+                    // request body is not of the kind
+                    None => return false,
+                };
 
-        ret
+                match matcher_condition {
+                    BodyCondition::Whole(matcher_condition_statement) => {
+                        matcher_condition_statement.is_match_value(request_body.whole())
+                    }
+                    BodyCondition::Keyed(matcher_condition) => {
+                        if matcher_condition.is_empty() {
+                            return false;
+                        }
+
+                        matcher_condition.iter().all(
+                            |(matcher_condition_key, matcher_condition_statement)| {
+                                // json values are compared with their types
+                                if let RequestBody::Json(value) = request_body {
+                                    let request_body_values =
+                                        self.json_values(value, matcher_condition_key);
+
+                                    if request_body_values.is_empty() {
+                                        return matcher_condition_statement
+                                            .is_match_json_value(None);
+                                    }
+
+                                    return request_body_values.into_iter().any(|x| {
+                                        matcher_condition_statement.is_match_json_value(Some(x))
+                                    });
+                                }
+
+                                let request_body_values = request_body.values(
+                                    matcher_condition_key,
+                                    self.jsonpaths.get(matcher_condition_key),
+                                );
+
+                                if request_body_values.is_empty() {
+                                    return matcher_condition_statement.is_match_value(None);
+                                }
+
+                                request_body_values.iter().any(|x| {
+                                    matcher_condition_statement.is_match_value(Some(x.as_str()))
+                                })
+                            },
+                        )
+                    }
+                }
+            })
+    }
+
+    /// compile regex in advance in each condition
+    pub fn compile_regex(&mut self) -> Result<(), regex::Error> {
+        for (_, matcher_condition) in self.conditions.iter_mut() {
+            match matcher_condition {
+                BodyCondition::Whole(statement) => statement.compile_regex()?,
+                BodyCondition::Keyed(matcher_condition) => {
+                    for (_, statement) in matcher_condition.iter_mut() {
+                        statement.compile_regex()?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// parse jsonpath of json condition keys in advance
+    pub fn parse_jsonpath(&mut self) -> Result<(), String> {
+        if let Some(BodyCondition::Keyed(matcher_condition)) = self.conditions.get(&BodyKind::Json)
+        {
+            for key in matcher_condition.keys() {
+                let jsonpath = JsonPath::parse(key)?;
+                self.jsonpaths.insert(key.to_owned(), jsonpath);
+            }
+        }
+        Ok(())
+    }
+
+    /// json values selected by jsonpath parsed from condition key
+    fn json_values<'a>(&self, value: &'a Value, key: &str) -> Vec<&'a Value> {
+        match self.jsonpaths.get(key) {
+            Some(jsonpath) => jsonpath.select(value),
+            None => vec![],
+        }
+    }
+
+    /// collect named capture groups from body values
+    pub fn collect_captures(&self, parsed_request: &ParsedRequest, captures: &mut Captures) {
+        for (body_kind, matcher_condition) in self.conditions.iter() {
+            let request_body = match RequestBody::new(body_kind, parsed_request) {
+                Some(x) => x,
+                None => continue,
+            };
+
+            match matcher_condition {
+                BodyCondition::Whole(statement) => {
+                    if let Some(request_body_value) = request_body.whole() {
+                        statement.collect_captures(request_body_value, captures);
+                    }
+                }
+                BodyCondition::Keyed(matcher_condition) => {
+                    for (matcher_condition_key, statement) in matcher_condition.iter() {
+                        let jsonpath = self.jsonpaths.get(matcher_condition_key);
+                        for request_body_value in
+                            request_body.values(matcher_condition_key, jsonpath)
+                        {
+                            statement.collect_captures(request_body_value.as_str(), captures);
+                        }
+                    }
+                }
+            }
+        }
     }
 
     /// validate
     pub fn validate(&self) -> bool {
-        if self.0.is_empty() {
+        if self.conditions.is_empty() {
             return false;
         }
 
-        for (_, body_kind_map) in self.0.iter() {
-            if body_kind_map.is_empty() {
-                return false;
+        for (body_kind, matcher_condition) in self.conditions.iter() {
+            match matcher_condition {
+                BodyCondition::Whole(_) if !body_kind.is_whole() => {
+                    log::error!("body.{} requires conditions keyed by path", body_kind);
+                    return false;
+                }
+                BodyCondition::Keyed(_) if body_kind.is_whole() => {
+                    log::error!(
+                        "body.{} requires a single condition on whole body",
+                        body_kind
+                    );
+                    return false;
+                }
+                BodyCondition::Keyed(matcher_condition) if matcher_condition.is_empty() => {
+                    return false;
+                }
+                BodyCondition::Whole(statement) if !statement.validate() => {
+                    return false;
+                }
+                BodyCondition::Keyed(matcher_condition)
+                    if !matcher_condition.values().all(|x| x.validate()) =>
+                {
+                    return false;
+                }
+                BodyCondition::Keyed(matcher_condition) if *body_kind == BodyKind::Json => {
+                    if let Some(key) = matcher_condition
+                        .keys()
+                        .find(|x| !self.jsonpaths.contains_key(*x))
+                    {
+                        log::error!("jsonpath is not parsed: {}", key);
+                        return false;
+                    }
+                }
+                _ => (),
             }
         }
 
@@ -75,14 +204,102 @@ impl Body {
     }
 }
 
+/// request body viewed as body kind
+enum RequestBody<'a> {
+    Json(&'a Value),
+    Form(&'a [(String, String)]),
+    Multipart(&'a [MultipartPart]),
+    Text(&'a str),
+    Xml(Document<'a>),
+}
+
+impl<'a> RequestBody<'a> {
+    /// none when request body is not of the kind
+    fn new(body_kind: &BodyKind, parsed_request: &'a ParsedRequest) -> Option<Self> {
+        match body_kind {
+            BodyKind::Json => Some(Self::Json(parsed_request.body_json.as_ref()?)),
+            BodyKind::Form => Some(Self::Form(parsed_request.body_form.as_ref()?)),
+            BodyKind::Multipart => Some(Self::Multipart(parsed_request.body_multipart.as_ref()?)),
+            BodyKind::Text => Some(Self::Text(body_str(parsed_request)?)),
+            BodyKind::Xml => match Document::parse(body_str(parsed_request)?) {
+                Ok(document) => Some(Self::Xml(document)),
+                Err(_) => None,
+            },
+        }
+    }
+
+    /// whole body value
+    fn whole(&self) -> Option<&str> {
+        match self {
+            Self::Text(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// values by condition key to compare. json values are selected by jsonpath parsed from key
+    fn values(&self, key: &str, jsonpath: Option<&JsonPath>) -> Vec<String> {
+        match self {
+            Self::Json(value) => jsonpath
+                .map(|x| x.select(value))
+                .unwrap_or_default()
+                .into_iter()
+                .map(json_value_str)
+                .collect(),
+            Self::Form(pairs) => pairs
+                .iter()
+                .filter(|(k, _)| k == key)
+                .map(|(_, v)| v.to_owned())
+                .collect(),
+            Self::Multipart(parts) => multipart_values(parts, key),
+            Self::Text(_) => vec![],
+            Self::Xml(document) => xml_values_by_path(document, key),
+        }
+    }
+}
+
+/// request body as utf-8 text
+fn body_str(parsed_request: &ParsedRequest) -> Option<&str> {
+    let body_bytes = parsed_request.body_bytes.as_ref()?;
+    std::str::from_utf8(body_bytes).ok()
+}
+
+/// multipart values by key: `name` for field content, `name.filename` or `name.content_type` for part attribute
+fn multipart_values(parts: &[MultipartPart], key: &str) -> Vec<String> {
+    let ret = parts
+        .iter()
+        .filter(|part| part.name == key)
+        .map(|part| part.content_str())
+        .collect::<Vec<String>>();
+    if !ret.is_empty() {
+        return ret;
+    }
+
+    let (name, attr) = match key.rsplit_once('.') {
+        Some(x) => x,
+        None => return ret,
+    };
+    parts
+        .iter()
+        .filter(|part| part.name == name)
+        .filter_map(|part| match attr {
+            "filename" => part.filename.clone(),
+            "content_type" => part.content_type.clone(),
+            _ => None,
+        })
+        .collect()
+}
+
 impl std::fmt::Display for Body {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for (body_kind, body_condition) in self.0.iter() {
-            let s = body_condition
-                .iter()
-                .map(|(key, statement)| format!("{}{}", key, statement))
-                .collect::<Vec<String>>()
-                .join(fmt_condition_connector().as_str());
+        for (body_kind, matcher_condition) in self.conditions.iter() {
+            let s = match matcher_condition {
+                BodyCondition::Whole(statement) => format!("body{}", statement),
+                BodyCondition::Keyed(matcher_condition) => matcher_condition
+                    .iter()
+                    .map(|(key, statement)| format!("{}{}", key, statement))
+                    .collect::<Vec<String>>()
+                    .join(fmt_condition_connector().as_str()),
+            };
 
             let _ = write!(f, "[{}] {}", body_kind, s);
         }
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+use std::collections::HashMap;
+
+use crate::core::server::routing::rule_set::rule::{
+    when::condition_statement::ConditionStatement, ConditionKey,
+};
+
+/// conditions on body of a kind: keyed by path / field name, or on the whole body
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum BodyCondition {
+    Keyed(HashMap<ConditionKey, ConditionStatement>),
+    Whole(ConditionStatement),
+}
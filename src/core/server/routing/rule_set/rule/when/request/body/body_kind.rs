@@ -4,12 +4,27 @@ use serde::Deserialize;
 #[serde(rename_all = "snake_case")]
 pub enum BodyKind {
     Json,
+    Form,
+    Multipart,
+    Text,
+    Xml,
+}
+
+impl BodyKind {
+    /// check if conditions are on the whole body instead of keyed values
+    pub fn is_whole(&self) -> bool {
+        matches!(self, Self::Text)
+    }
 }
 
 impl std::fmt::Display for BodyKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Json => write!(f, "JSON"),
+            Self::Form => write!(f, "Form"),
+            Self::Multipart => write!(f, "Multipart"),
+            Self::Text => write!(f, "Text"),
+            Self::Xml => write!(f, "XML"),
         }
     }
 }
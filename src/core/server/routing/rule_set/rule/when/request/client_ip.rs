@@ -0,0 +1,59 @@
+use serde::Deserialize;
+
+use std::net::SocketAddr;
+
+use crate::core::util::ip::IpRange;
+
+/// client ip addresses or cidr ranges. matches if client address is in any of them
+#[derive(Clone, Debug, Deserialize)]
+#[serde(try_from = "ClientIpConfig")]
+pub struct ClientIp(pub Vec<IpRange>);
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ClientIpConfig {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+impl TryFrom<ClientIpConfig> for ClientIp {
+    type Error = String;
+
+    fn try_from(value: ClientIpConfig) -> Result<Self, Self::Error> {
+        let ret = match value {
+            ClientIpConfig::Single(s) => vec![IpRange::parse(s.as_str())?],
+            ClientIpConfig::Multiple(v) => v
+                .iter()
+                .map(|s| IpRange::parse(s.as_str()))
+                .collect::<Result<Vec<IpRange>, String>>()?,
+        };
+        Ok(Self(ret))
+    }
+}
+
+impl ClientIp {
+    /// check if `client_ip` in `when` matches
+    pub fn is_match(&self, client_addr: &SocketAddr) -> bool {
+        self.0.iter().any(|x| x.contains(&client_addr.ip()))
+    }
+
+    /// validate
+    pub fn validate(&self) -> bool {
+        !self.0.is_empty()
+    }
+}
+
+impl std::fmt::Display for ClientIp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = self
+            .0
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let _ = write!(f, "[client_ip] in {}", s);
+
+        Ok(())
+    }
+}
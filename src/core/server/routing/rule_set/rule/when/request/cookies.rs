@@ -0,0 +1,86 @@
+use serde::Deserialize;
+
+use std::collections::HashMap;
+
+use super::util::fmt_condition_connector;
+use crate::core::server::routing::rule_set::rule::{
+    when::condition_statement::ConditionStatement, Captures, ConditionKey,
+};
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(transparent)]
+pub struct Cookies(pub HashMap<ConditionKey, ConditionStatement>);
+
+impl Cookies {
+    /// check if `cookies` in `when` matches
+    ///
+    /// when cookie name is repeated in request, any of its values matching is enough
+    pub fn is_match(&self, parsed_request_cookies: &[(String, String)]) -> bool {
+        self.0
+            .iter()
+            .all(|(matcher_cookie_name, matcher_cookie_value)| {
+                let mut parsed_request_cookie_values =
+                    cookie_values(parsed_request_cookies, matcher_cookie_name).peekable();
+
+                if parsed_request_cookie_values.peek().is_none() {
+                    return matcher_cookie_value.is_match_value(None);
+                }
+
+                parsed_request_cookie_values.any(|x| matcher_cookie_value.is_match_value(Some(x)))
+            })
+    }
+
+    /// compile regex in advance in each condition
+    pub fn compile_regex(&mut self) -> Result<(), regex::Error> {
+        for (_, statement) in self.0.iter_mut() {
+            statement.compile_regex()?;
+        }
+        Ok(())
+    }
+
+    /// collect named capture groups from cookie values
+    pub fn collect_captures(
+        &self,
+        parsed_request_cookies: &[(String, String)],
+        captures: &mut Captures,
+    ) {
+        for (matcher_cookie_name, matcher_cookie_value) in self.0.iter() {
+            for parsed_request_cookie_value in
+                cookie_values(parsed_request_cookies, matcher_cookie_name)
+            {
+                matcher_cookie_value.collect_captures(parsed_request_cookie_value, captures);
+            }
+        }
+    }
+
+    /// validate
+    pub fn validate(&self) -> bool {
+        !self.0.is_empty() && self.0.values().all(|x| x.validate())
+    }
+}
+
+/// values of the name in cookies
+fn cookie_values<'a>(
+    cookies: &'a [(String, String)],
+    name: &'a str,
+) -> impl Iterator<Item = &'a str> {
+    cookies
+        .iter()
+        .filter(move |(k, _)| k == name)
+        .map(|(_, v)| v.as_str())
+}
+
+impl std::fmt::Display for Cookies {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = self
+            .0
+            .iter()
+            .map(|(cookie_name, cookie_statement)| format!("{}{}", cookie_name, cookie_statement))
+            .collect::<Vec<String>>()
+            .join(fmt_condition_connector().as_str());
+
+        let _ = write!(f, "[cookies] {}", s);
+
+        Ok(())
+    }
+}
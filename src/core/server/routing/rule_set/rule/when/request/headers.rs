@@ -5,7 +5,7 @@ use std::collections::HashMap;
 
 use super::util::fmt_condition_connector;
 use crate::core::server::routing::rule_set::rule::{
-    when::condition_statement::ConditionStatement, ConditionKey,
+    when::condition_statement::ConditionStatement, Captures, ConditionKey,
 };
 
 #[derive(Clone, Debug, Deserialize)]
@@ -25,7 +25,7 @@ impl Headers {
             .all(|(matcher_header_key, matcher_header_value)| {
                 let parsed_request_header_value = match parsed_request_headers.get(matcher_header_key) {
                     Some(x) => x,
-                    None => return false,
+                    None => return matcher_header_value.is_match_value(None),
                 };
 
                 let parsed_request_header_value = match parsed_request_header_value.to_str() {
@@ -42,18 +42,40 @@ impl Headers {
                     }
                 };
 
-                let ret = matcher_header_value
-                    .op
-                    .clone()
-                    .unwrap_or_default()
-                    .is_match(parsed_request_header_value, &matcher_header_value.value);
+                let ret = matcher_header_value.is_match_value(Some(parsed_request_header_value));
                 ret
             })
     }
 
+    /// compile regex in advance in each condition
+    pub fn compile_regex(&mut self) -> Result<(), regex::Error> {
+        for (_, statement) in self.0.iter_mut() {
+            statement.compile_regex()?;
+        }
+        Ok(())
+    }
+
+    /// collect named capture groups from header values
+    pub fn collect_captures(
+        &self,
+        parsed_request_headers: &HeaderMap<HeaderValue>,
+        captures: &mut Captures,
+    ) {
+        for (matcher_header_key, matcher_header_value) in self.0.iter() {
+            let parsed_request_header_value = match parsed_request_headers.get(matcher_header_key) {
+                Some(x) => match x.to_str() {
+                    Ok(x) => x,
+                    Err(_) => continue,
+                },
+                None => continue,
+            };
+            matcher_header_value.collect_captures(parsed_request_header_value, captures);
+        }
+    }
+
     /// validate
     pub fn validate(&self) -> bool {
-        !self.0.is_empty()
+        !self.0.is_empty() && self.0.values().all(|x| x.validate())
     }
 }
 
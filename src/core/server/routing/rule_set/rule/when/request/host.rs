@@ -0,0 +1,66 @@
+use serde::Deserialize;
+
+use crate::core::server::routing::rule_set::rule::{
+    when::condition_statement::ConditionStatement, Captures,
+};
+
+/// condition on request host name (lowercase, without port)
+#[derive(Clone, Debug, Deserialize)]
+#[serde(from = "HostConfig")]
+pub struct Host(pub ConditionStatement);
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HostConfig {
+    Simple(String),
+    Detailed(ConditionStatement),
+}
+
+impl From<HostConfig> for Host {
+    fn from(value: HostConfig) -> Self {
+        match value {
+            HostConfig::Simple(s) => Self(ConditionStatement {
+                op: None,
+                value: Some(s.to_ascii_lowercase()),
+                case_insensitive: false,
+                trim: false,
+                regex: None,
+            }),
+            // host name is case insensitive. value may be regex, so it is not lowercased
+            HostConfig::Detailed(statement) => Self(ConditionStatement {
+                case_insensitive: true,
+                ..statement
+            }),
+        }
+    }
+}
+
+impl Host {
+    /// check if `host` in `when` matches
+    pub fn is_match(&self, parsed_request_host: Option<&str>) -> bool {
+        self.0.is_match_value(parsed_request_host)
+    }
+
+    /// compile regex in advance
+    pub fn compile_regex(&mut self) -> Result<(), regex::Error> {
+        self.0.compile_regex()
+    }
+
+    /// collect named capture groups from host
+    pub fn collect_captures(&self, parsed_request_host: Option<&str>, captures: &mut Captures) {
+        if let Some(parsed_request_host) = parsed_request_host {
+            self.0.collect_captures(parsed_request_host, captures);
+        }
+    }
+
+    /// validate
+    pub fn validate(&self) -> bool {
+        self.0.validate()
+    }
+}
+
+impl std::fmt::Display for Host {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[host]{}", self.0)
+    }
+}
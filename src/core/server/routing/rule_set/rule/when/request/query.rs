@@ -0,0 +1,86 @@
+use serde::Deserialize;
+
+use std::collections::HashMap;
+
+use super::util::fmt_condition_connector;
+use crate::core::server::routing::rule_set::rule::{
+    when::condition_statement::ConditionStatement, Captures, ConditionKey,
+};
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(transparent)]
+pub struct Query(pub HashMap<ConditionKey, ConditionStatement>);
+
+impl Query {
+    /// check if `query` in `when` matches
+    ///
+    /// when key is repeated in request, any of its values matching is enough
+    pub fn is_match(&self, parsed_request_query_params: &[(String, String)]) -> bool {
+        self.0
+            .iter()
+            .all(|(matcher_query_key, matcher_query_value)| {
+                let mut parsed_request_query_values =
+                    query_values(parsed_request_query_params, matcher_query_key).peekable();
+
+                if parsed_request_query_values.peek().is_none() {
+                    return matcher_query_value.is_match_value(None);
+                }
+
+                parsed_request_query_values.any(|x| matcher_query_value.is_match_value(Some(x)))
+            })
+    }
+
+    /// compile regex in advance in each condition
+    pub fn compile_regex(&mut self) -> Result<(), regex::Error> {
+        for (_, statement) in self.0.iter_mut() {
+            statement.compile_regex()?;
+        }
+        Ok(())
+    }
+
+    /// collect named capture groups from query values
+    pub fn collect_captures(
+        &self,
+        parsed_request_query_params: &[(String, String)],
+        captures: &mut Captures,
+    ) {
+        for (matcher_query_key, matcher_query_value) in self.0.iter() {
+            for parsed_request_query_value in
+                query_values(parsed_request_query_params, matcher_query_key)
+            {
+                matcher_query_value.collect_captures(parsed_request_query_value, captures);
+            }
+        }
+    }
+
+    /// validate
+    pub fn validate(&self) -> bool {
+        !self.0.is_empty() && self.0.values().all(|x| x.validate())
+    }
+}
+
+/// values of the key in query params
+fn query_values<'a>(
+    query_params: &'a [(String, String)],
+    key: &'a str,
+) -> impl Iterator<Item = &'a str> {
+    query_params
+        .iter()
+        .filter(move |(k, _)| k == key)
+        .map(|(_, v)| v.as_str())
+}
+
+impl std::fmt::Display for Query {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = self
+            .0
+            .iter()
+            .map(|(query_key, query_statement)| format!("{}{}", query_key, query_statement))
+            .collect::<Vec<String>>()
+            .join(fmt_condition_connector().as_str());
+
+        let _ = write!(f, "[query] {}", s);
+
+        Ok(())
+    }
+}
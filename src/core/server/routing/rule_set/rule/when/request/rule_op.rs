@@ -1,4 +1,6 @@
+use regex::{Regex, RegexBuilder};
 use serde::Deserialize;
+use serde_json::Value;
 
 #[cfg(test)]
 mod tests;
@@ -13,8 +15,23 @@ pub enum RuleOp {
     StartsWith,
     Contains,
     WildCard,
+    Regex,
+    Exists,
+    Absent,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    Between,
+    Type,
+    Length,
 }
 
+/// json type names available as value of type op
+const JSON_TYPE_NAMES: [&str; 7] = [
+    "string", "number", "integer", "boolean", "null", "array", "object",
+];
+
 impl Default for RuleOp {
     fn default() -> Self {
         Self::Equal
@@ -29,12 +46,24 @@ impl std::fmt::Display for RuleOp {
             Self::StartsWith => write!(f, " starts with "),
             Self::Contains => write!(f, " contains "),
             Self::WildCard => write!(f, " wild card matches "),
+            Self::Regex => write!(f, " regex matches "),
+            Self::Exists => write!(f, " exists"),
+            Self::Absent => write!(f, " is absent"),
+            Self::Gt => write!(f, " > "),
+            Self::Gte => write!(f, " >= "),
+            Self::Lt => write!(f, " < "),
+            Self::Lte => write!(f, " <= "),
+            Self::Between => write!(f, " between "),
+            Self::Type => write!(f, " is of type "),
+            Self::Length => write!(f, " has length "),
         }
     }
 }
 
 impl RuleOp {
     /// match with condition
+    ///
+    /// regex op compiles checker on each call. use `is_match_with_regex` with the one compiled in advance
     pub fn is_match(&self, text: &str, checker: &str) -> bool {
         match self {
             Self::Equal => text == checker,
@@ -42,6 +71,129 @@ impl RuleOp {
             Self::StartsWith => text.starts_with(checker),
             Self::Contains => text.contains(checker),
             Self::WildCard => glob_match(checker, text),
+            Self::Regex => match Regex::new(checker) {
+                Ok(regex) => regex.is_match(text),
+                Err(_) => false,
+            },
+            // text is given, that is, value exists
+            Self::Exists => true,
+            Self::Absent => false,
+            Self::Gt | Self::Gte | Self::Lt | Self::Lte | Self::Between => {
+                match parse_number(text) {
+                    Some(number) => self.is_number_match(number, checker),
+                    None => false,
+                }
+            }
+            // text value is always string
+            Self::Type => checker == "string",
+            Self::Length => checker.trim().parse::<usize>() == Ok(text.chars().count()),
+        }
+    }
+
+    /// match json value with condition, aware of its type
+    ///
+    /// returns none when op is not type-aware and value should be compared as text
+    pub fn is_match_json(&self, value: &Value, checker: &str) -> Option<bool> {
+        let ret = match self {
+            Self::Gt | Self::Gte | Self::Lt | Self::Lte | Self::Between => match value {
+                Value::Number(number) => number
+                    .as_f64()
+                    .is_some_and(|number| self.is_number_match(number, checker)),
+                Value::String(s) => self.is_match(s, checker),
+                _ => false,
+            },
+            Self::Type => match checker {
+                "integer" => value.is_i64() || value.is_u64(),
+                _ => json_type_name(value) == checker,
+            },
+            Self::Length => {
+                let len = match value {
+                    Value::Array(arr) => arr.len(),
+                    Value::Object(map) => map.len(),
+                    Value::String(s) => s.chars().count(),
+                    _ => return Some(false),
+                };
+                checker.trim().parse::<usize>() == Ok(len)
+            }
+            _ => return None,
+        };
+        Some(ret)
+    }
+
+    /// numeric comparison. checker of between is `min,max` (both inclusive)
+    fn is_number_match(&self, number: f64, checker: &str) -> bool {
+        if let Self::Between = self {
+            return match between_range(checker) {
+                Some((min, max)) => min <= number && number <= max,
+                None => false,
+            };
+        }
+
+        let checker = match parse_number(checker) {
+            Some(x) => x,
+            None => return false,
+        };
+        match self {
+            Self::Gt => number > checker,
+            Self::Gte => number >= checker,
+            Self::Lt => number < checker,
+            Self::Lte => number <= checker,
+            _ => false,
+        }
+    }
+
+    /// check if op is about existence of value instead of its content
+    pub fn is_existence_op(&self) -> bool {
+        matches!(self, Self::Exists | Self::Absent)
+    }
+
+    /// validate checker available for op
+    pub fn validate_checker(&self, checker: &str) -> Result<(), String> {
+        match self {
+            Self::Gt | Self::Gte | Self::Lt | Self::Lte => match parse_number(checker) {
+                Some(_) => Ok(()),
+                None => Err(format!("`{}` is not a number", checker)),
+            },
+            Self::Between => match between_range(checker) {
+                Some((min, max)) if min <= max => Ok(()),
+                _ => Err(format!(
+                    "`{}` is not a range such as `min,max` of numbers",
+                    checker
+                )),
+            },
+            Self::Type if !JSON_TYPE_NAMES.contains(&checker) => Err(format!(
+                "`{}` is not a type name (either of {})",
+                checker,
+                JSON_TYPE_NAMES.join(", ")
+            )),
+            Self::Length => match checker.trim().parse::<usize>() {
+                Ok(_) => Ok(()),
+                Err(_) => Err(format!("`{}` is not a length", checker)),
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// match with condition, using compiled regex if available
+    pub fn is_match_with_regex(&self, text: &str, checker: &str, regex: Option<&Regex>) -> bool {
+        match (self, regex) {
+            (Self::Regex, Some(regex)) => regex.is_match(text),
+            _ => self.is_match(text, checker),
+        }
+    }
+
+    /// compile checker when op is regex
+    pub fn compile_regex(
+        &self,
+        checker: &str,
+        case_insensitive: bool,
+    ) -> Result<Option<Regex>, regex::Error> {
+        match self {
+            Self::Regex => RegexBuilder::new(checker)
+                .case_insensitive(case_insensitive)
+                .build()
+                .map(Some),
+            _ => Ok(None),
         }
     }
 
@@ -54,3 +206,28 @@ impl RuleOp {
         }
     }
 }
+
+/// min and max parsed from `min,max`
+fn between_range(checker: &str) -> Option<(f64, f64)> {
+    let (min, max) = checker.split_once(',')?;
+    let min = parse_number(min)?;
+    let max = parse_number(max)?;
+    Some((min, max))
+}
+
+/// finite number. `NaN` and `inf` are not compared as number
+fn parse_number(s: &str) -> Option<f64> {
+    s.trim().parse::<f64>().ok().filter(|x| x.is_finite())
+}
+
+/// json type name of value
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::String(_) => "string",
+        Value::Number(_) => "number",
+        Value::Bool(_) => "boolean",
+        Value::Null => "null",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
@@ -243,3 +243,112 @@ fn wild_card() {
         );
     }
 }
+
+#[test]
+fn regex() {
+    let cases = vec![
+        TestCase {
+            text: "/users/123",
+            checker: r"^/users/\d+$",
+            expect: true,
+        },
+        TestCase {
+            text: "/users/abc",
+            checker: r"^/users/\d+$",
+            expect: false,
+        },
+        TestCase {
+            text: "/users/123/orders",
+            checker: r"^/users/(?<id>\d+)",
+            expect: true,
+        },
+        TestCase {
+            text: "a",
+            checker: "(",
+            expect: false,
+        },
+    ];
+    for case in cases {
+        assert_eq!(RuleOp::Regex.is_match(case.text, case.checker), case.expect);
+        let regex = RuleOp::Regex
+            .compile_regex(case.checker, false)
+            .ok()
+            .flatten();
+        assert_eq!(
+            RuleOp::Regex.is_match_with_regex(case.text, case.checker, regex.as_ref()),
+            case.expect
+        );
+    }
+}
+
+#[test]
+fn numeric() {
+    let cases = vec![
+        (RuleOp::Gt, "101", "100", true),
+        (RuleOp::Gt, "100", "100", false),
+        (RuleOp::Gte, "100", "100", true),
+        (RuleOp::Lt, "99.5", "100", true),
+        (RuleOp::Lte, "100.1", "100", false),
+        (RuleOp::Between, "10", "10,20", true),
+        (RuleOp::Between, "20.5", "10,20", false),
+        (RuleOp::Gt, "abc", "100", false),
+        (RuleOp::Gt, "inf", "100", false),
+        (RuleOp::Lt, "NaN", "100", false),
+    ];
+    for (op, text, checker, expect) in cases {
+        assert_eq!(op.is_match(text, checker), expect);
+    }
+}
+
+#[test]
+fn json_type_aware() {
+    use serde_json::json;
+
+    assert_eq!(RuleOp::Gt.is_match_json(&json!(150), "100"), Some(true));
+    assert_eq!(RuleOp::Gt.is_match_json(&json!("150"), "100"), Some(true));
+    assert_eq!(RuleOp::Gt.is_match_json(&json!(true), "0"), Some(false));
+    assert_eq!(RuleOp::Type.is_match_json(&json!(null), "null"), Some(true));
+    assert_eq!(
+        RuleOp::Type.is_match_json(&json!("1"), "number"),
+        Some(false)
+    );
+    assert_eq!(RuleOp::Type.is_match_json(&json!(1), "integer"), Some(true));
+    assert_eq!(
+        RuleOp::Type.is_match_json(&json!(1.5), "integer"),
+        Some(false)
+    );
+    assert_eq!(
+        RuleOp::Length.is_match_json(&json!([1, 2, 3]), "3"),
+        Some(true)
+    );
+    assert_eq!(RuleOp::Length.is_match_json(&json!("ab"), "3"), Some(false));
+    assert_eq!(
+        RuleOp::Length.is_match_json(&json!([1, 2, 3]), " 3"),
+        Some(true)
+    );
+    assert!(RuleOp::Length.is_match("abc", " 3 "));
+    assert_eq!(RuleOp::Equal.is_match_json(&json!(1), "1"), None);
+}
+
+#[test]
+fn validate_checker() {
+    assert!(RuleOp::Gt.validate_checker("1.5").is_ok());
+    assert!(RuleOp::Gt.validate_checker("abc").is_err());
+    assert!(RuleOp::Between.validate_checker("1,2").is_ok());
+    assert!(RuleOp::Between.validate_checker("2,1").is_err());
+    assert!(RuleOp::Between.validate_checker("1").is_err());
+    assert!(RuleOp::Type.validate_checker("array").is_ok());
+    assert!(RuleOp::Type.validate_checker("list").is_err());
+    assert!(RuleOp::Gt.validate_checker("NaN").is_err());
+    assert!(RuleOp::Lt.validate_checker("inf").is_err());
+    assert!(RuleOp::Between.validate_checker("-inf,1").is_err());
+    assert!(RuleOp::Length.validate_checker("-1").is_err());
+    assert!(RuleOp::Length.validate_checker(" 3").is_ok());
+    assert!(RuleOp::Equal.validate_checker("anything").is_ok());
+}
+
+#[test]
+fn regex_case_insensitive() {
+    let regex = RuleOp::Regex.compile_regex("^bearer ", true).ok().flatten();
+    assert!(RuleOp::Regex.is_match_with_regex("Bearer xyz", "^bearer ", regex.as_ref()));
+}
@@ -1,6 +1,11 @@
+use regex::Regex;
 use serde::Deserialize;
 
-use super::rule_op::RuleOp;
+mod path_template;
+
+use super::{rule_op::RuleOp, util::collect_named_captures};
+use crate::core::server::routing::rule_set::rule::Captures;
+use path_template::PathTemplate;
 
 #[derive(Clone, Debug, Deserialize)]
 #[serde(untagged)]
@@ -34,21 +39,67 @@ pub struct UrlPath {
     #[serde(skip)]
     pub value_with_prefix: String,
     pub op: Option<RuleOp>,
+    #[serde(skip)]
+    pub regex: Option<Regex>,
+    #[serde(skip)]
+    pub path_template: Option<PathTemplate>,
 }
 
 impl UrlPath {
     /// check if `url_path` in `when` matches
     pub fn is_match(&self, parsed_request_url_path: &str) -> bool {
+        if let Some(path_template) = self.path_template.as_ref() {
+            return path_template.is_match(parsed_request_url_path);
+        }
+
         let op = self.op.clone().unwrap_or_default();
         match op {
-            // contains op works with raw value (aka without url_path prefix)
+            // contains and regex ops work with raw value (aka without url_path prefix)
             RuleOp::Contains => op.is_match(parsed_request_url_path, self.value.as_str()),
+            RuleOp::Regex => op.is_match_with_regex(
+                parsed_request_url_path,
+                self.value.as_str(),
+                self.regex.as_ref(),
+            ),
             _ => op.is_match(parsed_request_url_path, self.value_with_prefix.as_str()),
         }
     }
 
-    /// validate (ok when deserialization is successful)
+    /// compile regex in advance when op is regex
+    pub fn compile_regex(&mut self) -> Result<(), regex::Error> {
+        self.regex = self
+            .op
+            .clone()
+            .unwrap_or_default()
+            .compile_regex(self.value.as_str(), false)?;
+        Ok(())
+    }
+
+    /// parse path template such as `/users/:id` when op is equal
+    pub fn compute_path_template(&mut self) {
+        self.path_template = match self.op.clone().unwrap_or_default() {
+            RuleOp::Equal => PathTemplate::parse(self.value_with_prefix.as_str()),
+            _ => None,
+        };
+    }
+
+    /// collect named segments of path template or named capture groups when op is regex
+    pub fn collect_captures(&self, parsed_request_url_path: &str, captures: &mut Captures) {
+        if let Some(path_template) = self.path_template.as_ref() {
+            if let Some(x) = path_template.captures(parsed_request_url_path) {
+                captures.extend(x);
+            }
+        }
+        if let Some(regex) = self.regex.as_ref() {
+            collect_named_captures(regex, parsed_request_url_path, captures);
+        }
+    }
+
+    /// validate (ok when deserialization is successful and path template is valid)
     pub fn validate(&self) -> bool {
-        true
+        match self.path_template.as_ref() {
+            Some(path_template) => path_template.validate(),
+            None => true,
+        }
     }
 }
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests;
+
+use crate::core::server::routing::rule_set::rule::Captures;
+
+/// url path template segment
+#[derive(Clone, Debug, PartialEq)]
+pub enum PathSegment {
+    Literal(String),
+    /// named segment written as `:name` or `{name}`
+    Param(String),
+}
+
+/// url path template such as `/users/:id/orders/{orderId}`
+#[derive(Clone, Debug)]
+pub struct PathTemplate {
+    pub segments: Vec<PathSegment>,
+}
+
+impl PathTemplate {
+    /// parse normalized url path. return none when no named segment is included
+    pub fn parse(url_path: &str) -> Option<Self> {
+        let segments = split_segments(url_path)
+            .iter()
+            .map(|segment| match param_name(segment) {
+                Some(name) => PathSegment::Param(name.to_owned()),
+                None => PathSegment::Literal((*segment).to_owned()),
+            })
+            .collect::<Vec<PathSegment>>();
+
+        if !segments.iter().any(|x| matches!(x, PathSegment::Param(_))) {
+            return None;
+        }
+
+        Some(Self { segments })
+    }
+
+    /// match with normalized request url path and return named segment values if matched
+    pub fn captures(&self, url_path: &str) -> Option<Captures> {
+        let request_segments = split_segments(url_path);
+        if request_segments.len() != self.segments.len() {
+            return None;
+        }
+
+        let mut ret = Captures::new();
+        for (segment, request_segment) in self.segments.iter().zip(request_segments.iter()) {
+            match segment {
+                PathSegment::Literal(s) => {
+                    if s != request_segment {
+                        return None;
+                    }
+                }
+                PathSegment::Param(name) => {
+                    // dot segments are never resource ids
+                    if matches!(*request_segment, "" | "." | "..") {
+                        return None;
+                    }
+                    ret.insert(name.to_owned(), (*request_segment).to_owned());
+                }
+            }
+        }
+
+        Some(ret)
+    }
+
+    /// check if matches with normalized request url path
+    pub fn is_match(&self, url_path: &str) -> bool {
+        self.captures(url_path).is_some()
+    }
+
+    /// validate: named segment must have unique and non-empty name
+    pub fn validate(&self) -> bool {
+        let mut names: Vec<&str> = vec![];
+        for segment in self.segments.iter() {
+            if let PathSegment::Param(name) = segment {
+                if name.is_empty() {
+                    log::error!("url_path has named segment without name");
+                    return false;
+                }
+                if names.contains(&name.as_str()) {
+                    log::error!("url_path has duplicate named segment: {}", name);
+                    return false;
+                }
+                names.push(name.as_str());
+            }
+        }
+        true
+    }
+}
+
+/// url path segments without leading slash
+fn split_segments(url_path: &str) -> Vec<&str> {
+    url_path
+        .strip_prefix("/")
+        .unwrap_or(url_path)
+        .split('/')
+        .collect()
+}
+
+/// name of named segment (`:name` or `{name}`)
+fn param_name(segment: &str) -> Option<&str> {
+    if let Some(name) = segment.strip_prefix(':') {
+        return Some(name);
+    }
+    segment.strip_prefix('{').and_then(|x| x.strip_suffix('}'))
+}
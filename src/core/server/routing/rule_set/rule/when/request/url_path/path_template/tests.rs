@@ -0,0 +1,61 @@
+use super::*;
+
+#[test]
+fn parse_without_param() {
+    assert!(PathTemplate::parse("/users").is_none());
+    assert!(PathTemplate::parse("/").is_none());
+}
+
+#[test]
+fn parse_params() {
+    let template = PathTemplate::parse("/users/:id/orders/{orderId}").unwrap();
+    assert_eq!(
+        template.segments,
+        vec![
+            PathSegment::Literal("users".to_owned()),
+            PathSegment::Param("id".to_owned()),
+            PathSegment::Literal("orders".to_owned()),
+            PathSegment::Param("orderId".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn captures() {
+    let template = PathTemplate::parse("/users/:id/orders/{orderId}").unwrap();
+
+    let captures = template.captures("/users/12/orders/a-3").unwrap();
+    assert_eq!(captures.get("id").unwrap(), "12");
+    assert_eq!(captures.get("orderId").unwrap(), "a-3");
+}
+
+#[test]
+fn segment_count_mismatch() {
+    let template = PathTemplate::parse("/users/:id").unwrap();
+    assert!(template.is_match("/users/1"));
+    assert!(!template.is_match("/users"));
+    assert!(!template.is_match("/users/1/orders"));
+}
+
+#[test]
+fn literal_mismatch() {
+    let template = PathTemplate::parse("/users/:id").unwrap();
+    assert!(!template.is_match("/members/1"));
+}
+
+#[test]
+fn dot_segment() {
+    let template = PathTemplate::parse("/users/:id").unwrap();
+    assert!(!template.is_match("/users/.."));
+}
+
+#[test]
+fn validate() {
+    assert!(PathTemplate::parse("/users/:id/orders/:orderId")
+        .unwrap()
+        .validate());
+    assert!(!PathTemplate::parse("/users/:id/orders/:id")
+        .unwrap()
+        .validate());
+    assert!(!PathTemplate::parse("/users/{}").unwrap().validate());
+}
@@ -1,6 +1,23 @@
 use console::style;
+use regex::Regex;
+
+use crate::core::server::routing::rule_set::rule::Captures;
 
 /// multiple condition string connector with style
 pub fn fmt_condition_connector() -> String {
     style(" && ").dim().to_string()
 }
+
+/// collect named capture groups matched in text
+pub fn collect_named_captures(regex: &Regex, text: &str, captures: &mut Captures) {
+    let matched = match regex.captures(text) {
+        Some(x) => x,
+        None => return,
+    };
+
+    for name in regex.capture_names().flatten() {
+        if let Some(value) = matched.name(name) {
+            captures.insert(name.to_owned(), value.as_str().to_owned());
+        }
+    }
+}
@@ -1,5 +1,4 @@
 use hyper::body::Bytes;
 
-use std::convert::Infallible;
-
-pub type BoxBody = http_body_util::combinators::BoxBody<Bytes, Infallible>;
+/// response body. io error aborts it on the way
+pub type BoxBody = http_body_util::combinators::BoxBody<Bytes, std::io::Error>;
@@ -1,4 +1,7 @@
 pub mod glob;
 pub mod http;
+pub mod ip;
 pub mod json;
+pub mod multipart;
 pub mod path;
+pub mod xml;
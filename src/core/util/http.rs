@@ -1,11 +1,17 @@
 use hyper::{
-    header::{HeaderValue, CONTENT_TYPE},
+    header::{HeaderValue, CONTENT_TYPE, COOKIE, HOST},
+    http::request::Parts,
     HeaderMap,
 };
 use tokio::time;
 
 use std::time::Duration;
 
+use super::multipart::header_param;
+
+#[cfg(test)]
+mod tests;
+
 /// check if content-type is application/json
 /// supporting case when "application/json; charset=utf-8"
 pub fn content_type_is_application_json(headers: &HeaderMap<HeaderValue>) -> Option<bool> {
@@ -27,6 +33,19 @@ pub fn content_type_is_application_json(headers: &HeaderMap<HeaderValue>) -> Opt
     Some(ret)
 }
 
+/// mime type of content-type in lowercase without parameters such as charset
+pub fn content_type_mime(headers: &HeaderMap<HeaderValue>) -> Option<String> {
+    let content_type = headers.get(CONTENT_TYPE)?.to_str().ok()?;
+    let mime = content_type.split(';').next().unwrap_or_default();
+    Some(mime.trim().to_ascii_lowercase())
+}
+
+/// boundary of multipart/form-data content-type
+pub fn multipart_boundary(headers: &HeaderMap<HeaderValue>) -> Option<String> {
+    let content_type = headers.get(CONTENT_TYPE)?.to_str().ok()?;
+    header_param(content_type, "boundary")
+}
+
 /// normalize url path
 pub fn normalize_url_path(url_path: &str, url_path_prefix: Option<&str>) -> String {
     let url_path_prefix = match url_path_prefix {
@@ -44,7 +63,103 @@ pub fn normalize_url_path(url_path: &str, url_path_prefix: Option<&str>) -> Stri
     format!("/{}", ret)
 }
 
+/// parse url query string (or form-urlencoded text) into percent-decoded key-value pairs
+///
+/// repeated keys are kept in order. key without `=` has empty value
+pub fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|x| !x.is_empty())
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) => (percent_decode(key), percent_decode(value)),
+            None => (percent_decode(pair), String::new()),
+        })
+        .collect()
+}
+
+/// decode percent-encoded text where `+` means space
+pub fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut ret: Vec<u8> = Vec::with_capacity(bytes.len());
+
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => ret.push(b' '),
+            b'%' => match (bytes.get(i + 1), bytes.get(i + 2)) {
+                (Some(high), Some(low)) => match hex_pair(*high, *low) {
+                    Some(x) => {
+                        ret.push(x);
+                        i += 2;
+                    }
+                    None => ret.push(b'%'),
+                },
+                _ => ret.push(b'%'),
+            },
+            x => ret.push(x),
+        }
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&ret).into_owned()
+}
+
+/// parse cookie headers into name-value pairs
+///
+/// multiple cookie headers are joined. value wrapped in double quotes is unquoted
+pub fn parse_cookies(headers: &HeaderMap<HeaderValue>) -> Vec<(String, String)> {
+    headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|x| x.to_str().ok())
+        .flat_map(|x| x.split(';'))
+        .filter_map(|pair| {
+            let (name, value) = pair.split_once('=')?;
+            let name = name.trim();
+            if name.is_empty() {
+                return None;
+            }
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|x| x.strip_suffix('"'))
+                .unwrap_or(value);
+            Some((name.to_owned(), value.to_owned()))
+        })
+        .collect()
+}
+
+/// request host name in lowercase without port
+///
+/// from host header, or uri authority (http/2)
+pub fn request_host(component_parts: &Parts) -> Option<String> {
+    let host = match component_parts.headers.get(HOST) {
+        Some(x) => x.to_str().ok()?.to_owned(),
+        None => component_parts.uri.authority()?.as_str().to_owned(),
+    };
+
+    let host = host.rsplit_once('@').map(|(_, x)| x).unwrap_or(&host);
+    let host = if host.starts_with('[') {
+        // ipv6 literal such as [::1]:3001
+        match host.find(']') {
+            Some(i) => &host[..=i],
+            None => host,
+        }
+    } else {
+        host.split_once(':').map(|(x, _)| x).unwrap_or(host)
+    };
+
+    Some(host.to_ascii_lowercase())
+}
+
+/// byte from two hex digits
+fn hex_pair(high: u8, low: u8) -> Option<u8> {
+    let high = (high as char).to_digit(16)?;
+    let low = (low as char).to_digit(16)?;
+    Some((high * 16 + low) as u8)
+}
+
 /// sleep
-pub async fn delay_response(milliseconds: u16) {
-    time::sleep(Duration::from_millis(milliseconds.into())).await
+pub async fn delay_response(milliseconds: u64) {
+    time::sleep(Duration::from_millis(milliseconds)).await
 }
@@ -0,0 +1,80 @@
+use super::*;
+
+#[test]
+fn percent_decode_plain() {
+    assert_eq!(percent_decode("abc"), "abc");
+    assert_eq!(percent_decode(""), "");
+}
+
+#[test]
+fn percent_decode_encoded() {
+    assert_eq!(percent_decode("a%20b"), "a b");
+    assert_eq!(percent_decode("a+b"), "a b");
+    assert_eq!(percent_decode("%E3%81%82"), "あ");
+    assert_eq!(percent_decode("a%2Fb%3d"), "a/b=");
+}
+
+#[test]
+fn percent_decode_invalid() {
+    assert_eq!(percent_decode("100%"), "100%");
+    assert_eq!(percent_decode("%zz"), "%zz");
+    assert_eq!(percent_decode("%2"), "%2");
+}
+
+#[test]
+fn parse_query_pairs() {
+    assert_eq!(
+        parse_query("page=2&tag=a&tag=b%20c&flag"),
+        vec![
+            ("page".to_owned(), "2".to_owned()),
+            ("tag".to_owned(), "a".to_owned()),
+            ("tag".to_owned(), "b c".to_owned()),
+            ("flag".to_owned(), String::new()),
+        ]
+    );
+    assert!(parse_query("").is_empty());
+}
+
+#[test]
+fn parse_cookies_pairs() {
+    let mut headers = HeaderMap::new();
+    headers.append(
+        COOKIE,
+        HeaderValue::from_static("session=abc; theme=\"dark\""),
+    );
+    headers.append(COOKIE, HeaderValue::from_static("lang=ja;;invalid"));
+    assert_eq!(
+        parse_cookies(&headers),
+        vec![
+            ("session".to_owned(), "abc".to_owned()),
+            ("theme".to_owned(), "dark".to_owned()),
+            ("lang".to_owned(), "ja".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn request_host_without_port() {
+    let host = |request: hyper::Request<()>| request_host(&request.into_parts().0);
+
+    let request = hyper::Request::builder()
+        .header(HOST, "Tenant-A.example.com:3001")
+        .body(())
+        .unwrap();
+    assert_eq!(host(request).as_deref(), Some("tenant-a.example.com"));
+
+    let request = hyper::Request::builder()
+        .header(HOST, "[::1]:3001")
+        .body(())
+        .unwrap();
+    assert_eq!(host(request).as_deref(), Some("[::1]"));
+
+    let request = hyper::Request::builder()
+        .uri("http://example.com/a")
+        .body(())
+        .unwrap();
+    assert_eq!(host(request).as_deref(), Some("example.com"));
+
+    let request = hyper::Request::builder().uri("/a").body(()).unwrap();
+    assert_eq!(host(request), None);
+}
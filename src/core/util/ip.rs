@@ -0,0 +1,78 @@
+use std::net::IpAddr;
+
+#[cfg(test)]
+mod tests;
+
+/// ip address range such as `10.0.0.0/8`. single address is range of full prefix length
+#[derive(Clone, Debug, PartialEq)]
+pub struct IpRange {
+    pub addr: IpAddr,
+    pub prefix_len: u8,
+}
+
+impl IpRange {
+    /// parse exact address or cidr notation
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let (addr, prefix_len) = match s.trim().split_once('/') {
+            Some((addr, prefix_len)) => (addr, Some(prefix_len)),
+            None => (s.trim(), None),
+        };
+
+        let addr = addr
+            .parse::<IpAddr>()
+            .map_err(|err| format!("invalid ip address `{}` ({})", s, err))?;
+        let max_prefix_len = max_prefix_len(&addr);
+
+        let prefix_len = match prefix_len {
+            Some(x) => match x.parse::<u8>() {
+                Ok(x) if x <= max_prefix_len => x,
+                _ => return Err(format!("invalid cidr prefix length `{}`", s)),
+            },
+            None => max_prefix_len,
+        };
+
+        Ok(Self { addr, prefix_len })
+    }
+
+    /// check if address is in range
+    ///
+    /// ipv4-mapped ipv6 address such as `::ffff:127.0.0.1` is treated as ipv4
+    pub fn contains(&self, addr: &IpAddr) -> bool {
+        match (self.addr.to_canonical(), addr.to_canonical()) {
+            (IpAddr::V4(range), IpAddr::V4(addr)) => {
+                masked(u32::from(range).into(), self.prefix_len, 32)
+                    == masked(u32::from(addr).into(), self.prefix_len, 32)
+            }
+            (IpAddr::V6(range), IpAddr::V6(addr)) => {
+                masked(u128::from(range), self.prefix_len, 128)
+                    == masked(u128::from(addr), self.prefix_len, 128)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// address bits of prefix length
+fn masked(bits: u128, prefix_len: u8, width: u8) -> u128 {
+    if prefix_len == 0 {
+        return 0;
+    }
+    bits >> (width - prefix_len.min(width))
+}
+
+fn max_prefix_len(addr: &IpAddr) -> u8 {
+    match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+impl std::fmt::Display for IpRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.prefix_len == max_prefix_len(&self.addr) {
+            write!(f, "{}", self.addr)
+        } else {
+            write!(f, "{}/{}", self.addr, self.prefix_len)
+        }
+    }
+}
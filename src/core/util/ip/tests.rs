@@ -0,0 +1,47 @@
+use super::*;
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+#[test]
+fn exact() {
+    let range = IpRange::parse("192.168.1.10").unwrap();
+    assert!(range.contains(&ip("192.168.1.10")));
+    assert!(!range.contains(&ip("192.168.1.11")));
+    assert!(range.contains(&ip("::ffff:192.168.1.10")));
+}
+
+#[test]
+fn cidr() {
+    let range = IpRange::parse("10.0.0.0/8").unwrap();
+    assert!(range.contains(&ip("10.1.2.3")));
+    assert!(!range.contains(&ip("11.0.0.1")));
+
+    let range = IpRange::parse("0.0.0.0/0").unwrap();
+    assert!(range.contains(&ip("8.8.8.8")));
+    assert!(!range.contains(&ip("::1")));
+
+    let range = IpRange::parse("2001:db8::/32").unwrap();
+    assert!(range.contains(&ip("2001:db8:1::1")));
+    assert!(!range.contains(&ip("2001:db9::1")));
+}
+
+#[test]
+fn invalid() {
+    assert!(IpRange::parse("localhost").is_err());
+    assert!(IpRange::parse("10.0.0.0/33").is_err());
+    assert!(IpRange::parse("10.0.0.0/x").is_err());
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        IpRange::parse("127.0.0.1").unwrap().to_string(),
+        "127.0.0.1"
+    );
+    assert_eq!(
+        IpRange::parse("10.0.0.0/8").unwrap().to_string(),
+        "10.0.0.0/8"
+    );
+}
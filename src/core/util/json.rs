@@ -4,6 +4,10 @@ use serde_json::Value;
 
 use crate::core::server::constant::ROOT_DIRECTORY_FILE_NAME;
 
+pub mod jsonpath;
+
+use jsonpath::JsonPath;
+
 pub const JSON_COMPATIBLE_EXTENSIONS: [&str; 3] = ["json", "json5", "csv"];
 
 /// resolve unknown path with json compatible extensions supplied
@@ -79,14 +83,26 @@ pub fn is_equivalent_json_file(request_path: &Path, entry_path: &Path) -> bool {
             .contains(&entry_ext.to_str().expect("failed to get entry file ext"))
 }
 
-/// get json value by jsonpath key
+/// get json value by jsonpath key (first match)
 pub fn json_value_by_jsonpath<'a>(value: &'a Value, jsonpath: &str) -> Option<&'a Value> {
-    let ret = jsonpath
-        .split('.')
-        .fold(Some(value), |current, key| match current {
-            Some(Value::Object(map)) => map.get(key),
-            Some(Value::Array(arr)) => key.parse::<usize>().ok().and_then(|i| arr.get(i)),
-            _ => None,
-        });
-    ret
+    json_values_by_jsonpath(value, jsonpath).into_iter().next()
+}
+
+/// get all json values matched by jsonpath key
+pub fn json_values_by_jsonpath<'a>(value: &'a Value, jsonpath: &str) -> Vec<&'a Value> {
+    match JsonPath::parse(jsonpath) {
+        Ok(jsonpath) => jsonpath.select(value),
+        Err(err) => {
+            log::warn!("{}", err);
+            vec![]
+        }
+    }
+}
+
+/// json value as string to compare: string as is, others in json notation
+pub fn json_value_str(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.to_owned(),
+        x => x.to_string(),
+    }
 }
@@ -0,0 +1,597 @@
+use serde_json::Value;
+
+#[cfg(test)]
+mod tests;
+
+/// parsed jsonpath
+///
+/// supports `$` root, `.name` and `['name']` (bracket notation for keys containing dots), `[0]` / `[-1]` index,
+/// `[0,2]` union, `[1:3]` slice, `*` / `[*]` wildcard, `..` recursive descent and `[?(@.price > 10)]` filter.
+/// path without `$` such as `a.b.0` is treated as dot notation from root where numeric name also indexes array
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonPath {
+    pub segments: Vec<Segment>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Segment {
+    Child(Vec<Selector>),
+    Descendant(Vec<Selector>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Selector {
+    Name(String),
+    Index(i64),
+    Slice(Option<i64>, Option<i64>, Option<i64>),
+    Wildcard,
+    Filter(FilterExpr),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum FilterExpr {
+    Or(Box<FilterExpr>, Box<FilterExpr>),
+    And(Box<FilterExpr>, Box<FilterExpr>),
+    Not(Box<FilterExpr>),
+    Exists(Operand),
+    Compare(Operand, CompareOp, Operand),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Operand {
+    /// `@` relative path
+    Current(JsonPath),
+    /// `$` absolute path
+    Root(JsonPath),
+    Literal(Value),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl JsonPath {
+    /// parse jsonpath string
+    pub fn parse(path: &str) -> Result<Self, String> {
+        let mut parser = Parser::new(path);
+        let ret = parser.path(true)?;
+        parser.skip_ws();
+        if !parser.is_end() {
+            return Err(parser.error("unexpected character"));
+        }
+        Ok(ret)
+    }
+
+    /// all values selected in value
+    pub fn select<'a>(&self, value: &'a Value) -> Vec<&'a Value> {
+        self.select_from(value, value)
+    }
+
+    /// member names when path consists only of them such as `$.a['b.c']`
+    pub fn member_names(&self) -> Option<Vec<&str>> {
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Child(selectors) if selectors.len() == 1 => match &selectors[0] {
+                    Selector::Name(name) => Some(name.as_str()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn select_from<'a>(&self, value: &'a Value, root: &'a Value) -> Vec<&'a Value> {
+        self.segments
+            .iter()
+            .fold(vec![value], |current, segment| match segment {
+                Segment::Child(selectors) => current
+                    .into_iter()
+                    .flat_map(|x| select_children(x, selectors, root))
+                    .collect(),
+                Segment::Descendant(selectors) => current
+                    .into_iter()
+                    .flat_map(descendants_or_self)
+                    .flat_map(|x| select_children(x, selectors, root))
+                    .collect(),
+            })
+    }
+}
+
+/// values selected by selectors in direct children
+fn select_children<'a>(
+    value: &'a Value,
+    selectors: &[Selector],
+    root: &'a Value,
+) -> Vec<&'a Value> {
+    selectors
+        .iter()
+        .flat_map(|selector| select_child(value, selector, root))
+        .collect()
+}
+
+fn select_child<'a>(value: &'a Value, selector: &Selector, root: &'a Value) -> Vec<&'a Value> {
+    match (selector, value) {
+        (Selector::Name(name), Value::Object(map)) => map.get(name).into_iter().collect(),
+        // numeric name in dot notation indexes array
+        (Selector::Name(name), Value::Array(arr)) => name
+            .parse::<usize>()
+            .ok()
+            .and_then(|i| arr.get(i))
+            .into_iter()
+            .collect(),
+        (Selector::Index(index), Value::Array(arr)) => normalize_index(*index, arr.len())
+            .and_then(|i| arr.get(i))
+            .into_iter()
+            .collect(),
+        (Selector::Slice(start, end, step), Value::Array(arr)) => {
+            slice_indexes(*start, *end, *step, arr.len())
+                .into_iter()
+                .filter_map(|i| arr.get(i))
+                .collect()
+        }
+        (Selector::Wildcard, _) => children(value),
+        (Selector::Filter(expr), _) => children(value)
+            .into_iter()
+            .filter(|x| expr.evaluate(x, root))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// direct children of array or object
+fn children(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(arr) => arr.iter().collect(),
+        Value::Object(map) => map.values().collect(),
+        _ => vec![],
+    }
+}
+
+/// value itself and all of its descendants
+fn descendants_or_self(value: &Value) -> Vec<&Value> {
+    let mut ret = vec![value];
+    for child in children(value) {
+        ret.extend(descendants_or_self(child));
+    }
+    ret
+}
+
+/// index from end when negative
+fn normalize_index(index: i64, len: usize) -> Option<usize> {
+    let index = if index < 0 { len as i64 + index } else { index };
+    if index < 0 {
+        None
+    } else {
+        Some(index as usize)
+    }
+}
+
+/// array indexes selected by slice
+fn slice_indexes(
+    start: Option<i64>,
+    end: Option<i64>,
+    step: Option<i64>,
+    len: usize,
+) -> Vec<usize> {
+    let len = len as i64;
+    let step = step.unwrap_or(1);
+    if step == 0 {
+        return vec![];
+    }
+
+    let normalize = |x: i64| if x < 0 { len + x } else { x };
+
+    let mut ret = vec![];
+    if step > 0 {
+        let start = normalize(start.unwrap_or(0)).clamp(0, len);
+        let end = normalize(end.unwrap_or(len)).clamp(0, len);
+        let mut i = start;
+        while i < end {
+            ret.push(i as usize);
+            i += step;
+        }
+    } else {
+        let start = normalize(start.unwrap_or(len - 1)).clamp(-1, len - 1);
+        let end = match end {
+            Some(end) => normalize(end).clamp(-1, len - 1),
+            None => -1,
+        };
+        let mut i = start;
+        while i > end {
+            ret.push(i as usize);
+            i += step;
+        }
+    }
+    ret
+}
+
+impl FilterExpr {
+    /// evaluate filter on current value
+    fn evaluate(&self, current: &Value, root: &Value) -> bool {
+        match self {
+            Self::Or(a, b) => a.evaluate(current, root) || b.evaluate(current, root),
+            Self::And(a, b) => a.evaluate(current, root) && b.evaluate(current, root),
+            Self::Not(a) => !a.evaluate(current, root),
+            Self::Exists(operand) => match operand {
+                Operand::Literal(value) => !matches!(value, Value::Null | Value::Bool(false)),
+                _ => !operand.values(current, root).is_empty(),
+            },
+            Self::Compare(a, op, b) => {
+                let a = a.values(current, root);
+                let b = b.values(current, root);
+                match (a.len(), b.len()) {
+                    // both missing are equal
+                    (0, 0) => matches!(op, CompareOp::Eq | CompareOp::Le | CompareOp::Ge),
+                    (1, 1) => compare(a[0], op, b[0]),
+                    _ => matches!(op, CompareOp::Ne),
+                }
+            }
+        }
+    }
+}
+
+impl Operand {
+    fn values<'a>(&'a self, current: &'a Value, root: &'a Value) -> Vec<&'a Value> {
+        match self {
+            Self::Current(path) => path.select_from(current, root),
+            Self::Root(path) => path.select_from(root, root),
+            Self::Literal(value) => vec![value],
+        }
+    }
+}
+
+/// compare two json values
+fn compare(a: &Value, op: &CompareOp, b: &Value) -> bool {
+    let ordering = match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64().partial_cmp(&b.as_f64()),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        _ => None,
+    };
+
+    match op {
+        CompareOp::Eq => match ordering {
+            Some(x) => x.is_eq(),
+            None => a == b,
+        },
+        CompareOp::Ne => match ordering {
+            Some(x) => x.is_ne(),
+            None => a != b,
+        },
+        CompareOp::Lt => ordering.is_some_and(|x| x.is_lt()),
+        CompareOp::Le => ordering.is_some_and(|x| x.is_le()),
+        CompareOp::Gt => ordering.is_some_and(|x| x.is_gt()),
+        CompareOp::Ge => ordering.is_some_and(|x| x.is_ge()),
+    }
+}
+
+/// recursive descent parser
+struct Parser<'a> {
+    chars: Vec<char>,
+    pos: usize,
+    src: &'a str,
+    /// nesting depth of filter expressions where operators delimit names
+    filter_depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(src: &'a str) -> Self {
+        Self {
+            chars: src.chars().collect(),
+            pos: 0,
+            src,
+            filter_depth: 0,
+        }
+    }
+
+    fn error(&self, message: &str) -> String {
+        format!(
+            "invalid jsonpath `{}`: {} at {}",
+            self.src, message, self.pos
+        )
+    }
+
+    fn is_end(&self) -> bool {
+        self.pos >= self.chars.len()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn starts_with(&self, s: &str) -> bool {
+        s.chars()
+            .enumerate()
+            .all(|(i, c)| self.chars.get(self.pos + i) == Some(&c))
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        if self.starts_with(s) {
+            self.pos += s.chars().count();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, s: &str) -> Result<(), String> {
+        if self.eat(s) {
+            Ok(())
+        } else {
+            Err(self.error(format!("`{}` expected", s).as_str()))
+        }
+    }
+
+    fn skip_ws(&mut self) {
+        while self.peek().is_some_and(|c| c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    /// path: `$` followed by segments, or dot notation without `$` on top level
+    fn path(&mut self, is_top_level: bool) -> Result<JsonPath, String> {
+        let mut segments = vec![];
+
+        if !self.eat("$") && is_top_level && !self.is_end() {
+            // dot notation without `$`
+            if !self.starts_with(".") && !self.starts_with("[") {
+                segments.push(Segment::Child(vec![Selector::Name(self.dot_name()?)]));
+            }
+        }
+
+        segments.extend(self.segments()?);
+        Ok(JsonPath { segments })
+    }
+
+    /// path after `@` or `$` in filter
+    fn relative_path(&mut self) -> Result<JsonPath, String> {
+        Ok(JsonPath {
+            segments: self.segments()?,
+        })
+    }
+
+    fn segments(&mut self) -> Result<Vec<Segment>, String> {
+        let mut ret = vec![];
+        loop {
+            if self.eat("..") {
+                let selectors = if self.starts_with("[") {
+                    self.bracket()?
+                } else if self.eat("*") {
+                    vec![Selector::Wildcard]
+                } else {
+                    vec![Selector::Name(self.dot_name()?)]
+                };
+                ret.push(Segment::Descendant(selectors));
+            } else if self.eat(".") {
+                let selectors = if self.eat("*") {
+                    vec![Selector::Wildcard]
+                } else {
+                    vec![Selector::Name(self.dot_name()?)]
+                };
+                ret.push(Segment::Child(selectors));
+            } else if self.starts_with("[") {
+                ret.push(Segment::Child(self.bracket()?));
+            } else {
+                break;
+            }
+        }
+        Ok(ret)
+    }
+
+    /// name in dot notation: until `.`, `[` or end (or operators in filter)
+    fn dot_name(&mut self) -> Result<String, String> {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            let is_delimiter = matches!(c, '.' | '[')
+                || (0 < self.filter_depth
+                    && (c.is_whitespace()
+                        || matches!(c, ']' | ')' | '(' | '=' | '!' | '<' | '>' | '&' | '|' | ',')));
+            if is_delimiter {
+                break;
+            }
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Err(self.error("name expected"));
+        }
+        Ok(self.chars[start..self.pos].iter().collect())
+    }
+
+    /// bracket selectors: `['a','b']`, `[0,1]`, `[1:3]`, `[*]`, `[?(...)]`
+    fn bracket(&mut self) -> Result<Vec<Selector>, String> {
+        self.expect("[")?;
+        let mut ret = vec![];
+        loop {
+            self.skip_ws();
+            ret.push(self.selector()?);
+            self.skip_ws();
+            if self.eat(",") {
+                continue;
+            }
+            self.expect("]")?;
+            break;
+        }
+        Ok(ret)
+    }
+
+    fn selector(&mut self) -> Result<Selector, String> {
+        match self.peek() {
+            Some('\'') | Some('"') => Ok(Selector::Name(self.string()?)),
+            Some('*') => {
+                self.pos += 1;
+                Ok(Selector::Wildcard)
+            }
+            Some('?') => {
+                self.pos += 1;
+                self.skip_ws();
+                self.filter_depth += 1;
+                let ret = self.or_expr();
+                self.filter_depth -= 1;
+                Ok(Selector::Filter(ret?))
+            }
+            _ => {
+                let start = self.integer()?;
+                self.skip_ws();
+                if !self.eat(":") {
+                    return match start {
+                        Some(x) => Ok(Selector::Index(x)),
+                        None => Err(self.error("selector expected")),
+                    };
+                }
+                self.skip_ws();
+                let end = self.integer()?;
+                self.skip_ws();
+                let step = if self.eat(":") {
+                    self.skip_ws();
+                    self.integer()?
+                } else {
+                    None
+                };
+                Ok(Selector::Slice(start, end, step))
+            }
+        }
+    }
+
+    fn integer(&mut self) -> Result<Option<i64>, String> {
+        let start = self.pos;
+        if self.starts_with("-") {
+            self.pos += 1;
+        }
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Ok(None);
+        }
+        let s: String = self.chars[start..self.pos].iter().collect();
+        s.parse::<i64>()
+            .map(Some)
+            .map_err(|_| self.error("integer expected"))
+    }
+
+    /// quoted string with backslash escapes
+    fn string(&mut self) -> Result<String, String> {
+        let quote = self.peek().ok_or_else(|| self.error("string expected"))?;
+        self.pos += 1;
+        let mut ret = String::new();
+        loop {
+            match self.peek() {
+                Some('\\') => {
+                    self.pos += 1;
+                    match self.peek() {
+                        Some(c) => ret.push(c),
+                        None => return Err(self.error("unterminated string")),
+                    }
+                }
+                Some(c) if c == quote => {
+                    self.pos += 1;
+                    return Ok(ret);
+                }
+                Some(c) => ret.push(c),
+                None => return Err(self.error("unterminated string")),
+            }
+            self.pos += 1;
+        }
+    }
+
+    fn or_expr(&mut self) -> Result<FilterExpr, String> {
+        let mut ret = self.and_expr()?;
+        loop {
+            self.skip_ws();
+            if !self.eat("||") {
+                return Ok(ret);
+            }
+            self.skip_ws();
+            ret = FilterExpr::Or(Box::new(ret), Box::new(self.and_expr()?));
+        }
+    }
+
+    fn and_expr(&mut self) -> Result<FilterExpr, String> {
+        let mut ret = self.unary_expr()?;
+        loop {
+            self.skip_ws();
+            if !self.eat("&&") {
+                return Ok(ret);
+            }
+            self.skip_ws();
+            ret = FilterExpr::And(Box::new(ret), Box::new(self.unary_expr()?));
+        }
+    }
+
+    fn unary_expr(&mut self) -> Result<FilterExpr, String> {
+        self.skip_ws();
+        if self.starts_with("!=") {
+            return Err(self.error("operand expected"));
+        }
+        if self.eat("!") {
+            return Ok(FilterExpr::Not(Box::new(self.unary_expr()?)));
+        }
+        if self.eat("(") {
+            let ret = self.or_expr()?;
+            self.skip_ws();
+            self.expect(")")?;
+            return Ok(ret);
+        }
+
+        let left = self.operand()?;
+        self.skip_ws();
+        let op = if self.eat("==") {
+            CompareOp::Eq
+        } else if self.eat("!=") {
+            CompareOp::Ne
+        } else if self.eat("<=") {
+            CompareOp::Le
+        } else if self.eat(">=") {
+            CompareOp::Ge
+        } else if self.eat("<") {
+            CompareOp::Lt
+        } else if self.eat(">") {
+            CompareOp::Gt
+        } else {
+            return Ok(FilterExpr::Exists(left));
+        };
+        self.skip_ws();
+        let right = self.operand()?;
+        Ok(FilterExpr::Compare(left, op, right))
+    }
+
+    fn operand(&mut self) -> Result<Operand, String> {
+        if self.eat("@") {
+            return Ok(Operand::Current(self.relative_path()?));
+        }
+        if self.eat("$") {
+            return Ok(Operand::Root(self.relative_path()?));
+        }
+        if matches!(self.peek(), Some('\'') | Some('"')) {
+            return Ok(Operand::Literal(Value::String(self.string()?)));
+        }
+        for (keyword, value) in [
+            ("true", Value::Bool(true)),
+            ("false", Value::Bool(false)),
+            ("null", Value::Null),
+        ] {
+            if self.eat(keyword) {
+                return Ok(Operand::Literal(value));
+            }
+        }
+
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'))
+        {
+            self.pos += 1;
+        }
+        let s: String = self.chars[start..self.pos].iter().collect();
+        match serde_json::from_str::<Value>(s.as_str()) {
+            Ok(value @ Value::Number(_)) => Ok(Operand::Literal(value)),
+            _ => Err(self.error("operand expected")),
+        }
+    }
+}
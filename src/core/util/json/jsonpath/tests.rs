@@ -0,0 +1,127 @@
+use serde_json::json;
+
+use super::*;
+
+fn value() -> Value {
+    json!({
+        "store": {
+            "name": "main",
+            "items": [
+                { "sku": "a-1", "price": 5, "tags": ["new"] },
+                { "sku": "b-2", "price": 15 },
+                { "sku": "c-3", "price": 25, "tags": ["sale"] }
+            ]
+        },
+        "a.b": { "c": true }
+    })
+}
+
+fn values(path: &str) -> Vec<Value> {
+    let value = value();
+    JsonPath::parse(path)
+        .unwrap()
+        .select(&value)
+        .into_iter()
+        .cloned()
+        .collect()
+}
+
+#[test]
+fn dot_notation() {
+    assert_eq!(values("store.name"), vec![json!("main")]);
+    assert_eq!(values("store.items.1.sku"), vec![json!("b-2")]);
+    assert_eq!(values("$.store.name"), vec![json!("main")]);
+    assert!(values("store.missing").is_empty());
+}
+
+#[test]
+fn bracket_notation() {
+    assert_eq!(values("$['a.b'].c"), vec![json!(true)]);
+    assert_eq!(values("$[\"store\"]['name']"), vec![json!("main")]);
+    assert_eq!(values("$.store.items[0].sku"), vec![json!("a-1")]);
+    assert_eq!(values("$.store.items[-1].sku"), vec![json!("c-3")]);
+}
+
+#[test]
+fn wildcard() {
+    assert_eq!(
+        values("$.store.items[*].sku"),
+        vec![json!("a-1"), json!("b-2"), json!("c-3")]
+    );
+    assert_eq!(values("store.items.*.price").len(), 3);
+}
+
+#[test]
+fn union_and_slice() {
+    assert_eq!(
+        values("$.store.items[0,2].sku"),
+        vec![json!("a-1"), json!("c-3")]
+    );
+    assert_eq!(
+        values("$.store.items[1:].sku"),
+        vec![json!("b-2"), json!("c-3")]
+    );
+    assert_eq!(
+        values("$.store.items[::-2].sku"),
+        vec![json!("c-3"), json!("a-1")]
+    );
+}
+
+#[test]
+fn recursive_descent() {
+    assert_eq!(
+        values("$..sku"),
+        vec![json!("a-1"), json!("b-2"), json!("c-3")]
+    );
+    assert_eq!(values("$..tags[0]"), vec![json!("new"), json!("sale")]);
+}
+
+#[test]
+fn filter() {
+    assert_eq!(
+        values("$.store.items[?(@.price > 10)].sku"),
+        vec![json!("b-2"), json!("c-3")]
+    );
+    assert_eq!(
+        values("$.store.items[?(@.sku == 'a-1' || @.price >= 25)].sku"),
+        vec![json!("a-1"), json!("c-3")]
+    );
+    assert_eq!(
+        values("$.store.items[?(@.tags && !(@.price < 10))].sku"),
+        vec![json!("c-3")]
+    );
+    assert_eq!(values("$..[?(@ == 'sale')]"), vec![json!("sale")]);
+}
+
+#[test]
+fn member_names() {
+    assert_eq!(
+        JsonPath::parse("$.data['records.v1']")
+            .unwrap()
+            .member_names(),
+        Some(vec!["data", "records.v1"])
+    );
+    assert_eq!(
+        JsonPath::parse("data.records").unwrap().member_names(),
+        Some(vec!["data", "records"])
+    );
+    assert!(JsonPath::parse("$.data[*]")
+        .unwrap()
+        .member_names()
+        .is_none());
+}
+
+#[test]
+fn invalid() {
+    assert!(JsonPath::parse("$.store[").is_err());
+    assert!(JsonPath::parse("$.store['name").is_err());
+    assert!(JsonPath::parse("$.store[?(@.price >)]").is_err());
+    assert!(JsonPath::parse("$.store..").is_err());
+}
+
+#[test]
+fn dot_notation_name_with_space() {
+    let value = json!({ "user name": "alice" });
+    let jsonpath = JsonPath::parse("user name").unwrap();
+    assert_eq!(jsonpath.select(&value), vec![&json!("alice")]);
+}
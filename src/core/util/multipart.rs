@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests;
+
+/// part of multipart/form-data body
+#[derive(Clone, Debug)]
+pub struct MultipartPart {
+    pub name: String,
+    pub filename: Option<String>,
+    pub content_type: Option<String>,
+    pub content: Vec<u8>,
+}
+
+impl MultipartPart {
+    /// content as text (invalid utf-8 sequences are replaced)
+    pub fn content_str(&self) -> String {
+        String::from_utf8_lossy(&self.content).into_owned()
+    }
+}
+
+/// parse multipart/form-data body with boundary
+pub fn parse_multipart(body: &[u8], boundary: &str) -> Vec<MultipartPart> {
+    let delimiter = format!("--{}", boundary);
+    let delimiter = delimiter.as_bytes();
+
+    let mut ret = vec![];
+
+    let mut rest = match find(body, delimiter) {
+        Some(pos) => &body[pos + delimiter.len()..],
+        None => return ret,
+    };
+
+    loop {
+        // closing delimiter
+        if rest.starts_with(b"--") {
+            break;
+        }
+        rest = rest.strip_prefix(b"\r\n").unwrap_or(rest);
+
+        let end = match find(rest, delimiter) {
+            Some(x) => x,
+            None => break,
+        };
+        let part = &rest[..end];
+        let part = part.strip_suffix(b"\r\n").unwrap_or(part);
+
+        if let Some(part) = parse_part(part) {
+            ret.push(part);
+        }
+
+        rest = &rest[end + delimiter.len()..];
+    }
+
+    ret
+}
+
+/// parse single part consisting of headers and content
+fn parse_part(part: &[u8]) -> Option<MultipartPart> {
+    let headers_end = find(part, b"\r\n\r\n")?;
+    let headers = String::from_utf8_lossy(&part[..headers_end]);
+    let content = part[headers_end + 4..].to_vec();
+
+    let mut name = None;
+    let mut filename = None;
+    let mut content_type = None;
+
+    for line in headers.split("\r\n") {
+        let (key, value) = match line.split_once(':') {
+            Some(x) => x,
+            None => continue,
+        };
+        match key.trim().to_ascii_lowercase().as_str() {
+            "content-disposition" => {
+                name = header_param(value, "name");
+                filename = header_param(value, "filename");
+            }
+            "content-type" => content_type = Some(value.trim().to_owned()),
+            _ => (),
+        }
+    }
+
+    Some(MultipartPart {
+        name: name?,
+        filename,
+        content_type,
+        content,
+    })
+}
+
+/// parameter value in header value such as `form-data; name="a"`
+pub fn header_param(header_value: &str, param_name: &str) -> Option<String> {
+    header_value.split(';').skip(1).find_map(|param| {
+        let (key, value) = param.split_once('=')?;
+        if !key.trim().eq_ignore_ascii_case(param_name) {
+            return None;
+        }
+        let value = value.trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|x| x.strip_suffix('"'))
+            .unwrap_or(value);
+        Some(value.to_owned())
+    })
+}
+
+/// position of needle in haystack
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
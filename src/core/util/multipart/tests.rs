@@ -0,0 +1,47 @@
+use super::*;
+
+const BODY: &[u8] = b"--XyZ\r\n\
+Content-Disposition: form-data; name=\"title\"\r\n\
+\r\n\
+hello\r\n\
+--XyZ\r\n\
+Content-Disposition: form-data; name=\"avatar\"; filename=\"me.png\"\r\n\
+Content-Type: image/png\r\n\
+\r\n\
+\x89PNG\r\n\
+--XyZ--\r\n";
+
+#[test]
+fn parse_parts() {
+    let parts = parse_multipart(BODY, "XyZ");
+    assert_eq!(parts.len(), 2);
+
+    assert_eq!(parts[0].name, "title");
+    assert_eq!(parts[0].filename, None);
+    assert_eq!(parts[0].content_type, None);
+    assert_eq!(parts[0].content_str(), "hello");
+
+    assert_eq!(parts[1].name, "avatar");
+    assert_eq!(parts[1].filename.as_deref(), Some("me.png"));
+    assert_eq!(parts[1].content_type.as_deref(), Some("image/png"));
+    assert_eq!(parts[1].content, b"\x89PNG");
+}
+
+#[test]
+fn parse_wrong_boundary() {
+    assert!(parse_multipart(BODY, "other").is_empty());
+}
+
+#[test]
+fn header_param_value() {
+    let header_value = "multipart/form-data; boundary=\"XyZ\"; charset=utf-8";
+    assert_eq!(
+        header_param(header_value, "boundary").as_deref(),
+        Some("XyZ")
+    );
+    assert_eq!(
+        header_param(header_value, "charset").as_deref(),
+        Some("utf-8")
+    );
+    assert_eq!(header_param(header_value, "name"), None);
+}
@@ -0,0 +1,151 @@
+use roxmltree::{Document, Node};
+
+#[cfg(test)]
+mod tests;
+
+/// step of xpath-like path
+enum XmlPathStep<'a> {
+    /// element by local name (`*` matches any) with optional 1-based position
+    Element {
+        name: &'a str,
+        position: Option<usize>,
+        descendant: bool,
+    },
+    Attribute(&'a str),
+    Text,
+}
+
+/// get values in xml document by xpath-like path
+///
+/// supports `/a/b` (child), `//b` (descendant), `*`, `b[2]` (1-based position), `@attr` and `text()`.
+/// namespace prefix in path is ignored and elements are matched by local name
+pub fn xml_values_by_path(document: &Document, path: &str) -> Vec<String> {
+    let steps = match parse_path(path) {
+        Some(x) => x,
+        None => return vec![],
+    };
+
+    let mut nodes = vec![document.root()];
+    for step in steps.iter() {
+        match step {
+            XmlPathStep::Element {
+                name,
+                position,
+                descendant,
+            } => {
+                nodes = nodes
+                    .iter()
+                    .flat_map(|node| {
+                        let candidates = if *descendant {
+                            node.descendants().skip(1).collect::<Vec<Node>>()
+                        } else {
+                            node.children().collect::<Vec<Node>>()
+                        };
+                        let matched = candidates
+                            .into_iter()
+                            .filter(|x| {
+                                x.is_element() && (*name == "*" || x.tag_name().name() == *name)
+                            })
+                            .collect::<Vec<Node>>();
+                        match position {
+                            Some(position) => {
+                                matched.into_iter().nth(position - 1).into_iter().collect()
+                            }
+                            None => matched,
+                        }
+                    })
+                    .collect();
+            }
+            XmlPathStep::Attribute(name) => {
+                return nodes
+                    .iter()
+                    .filter_map(|node| {
+                        node.attributes()
+                            .find(|x| x.name() == *name)
+                            .map(|x| x.value().to_owned())
+                    })
+                    .collect();
+            }
+            XmlPathStep::Text => break,
+        }
+    }
+
+    nodes
+        .iter()
+        .filter(|node| node.is_element())
+        .map(|node| element_text(node))
+        .collect()
+}
+
+/// parse xpath-like path into steps. none when path is invalid
+fn parse_path(path: &str) -> Option<Vec<XmlPathStep<'_>>> {
+    let mut ret = vec![];
+
+    let mut rest = path.trim();
+    loop {
+        let descendant = rest.starts_with("//");
+        rest = rest.trim_start_matches('/');
+        if rest.is_empty() {
+            break;
+        }
+
+        let end = rest.find('/').unwrap_or(rest.len());
+        let step = &rest[..end];
+        rest = &rest[end..];
+
+        let is_last = rest.is_empty();
+        if let Some(name) = step.strip_prefix('@') {
+            if !is_last || name.is_empty() {
+                return None;
+            }
+            ret.push(XmlPathStep::Attribute(local_name(name)));
+            continue;
+        }
+        if step == "text()" {
+            if !is_last {
+                return None;
+            }
+            ret.push(XmlPathStep::Text);
+            continue;
+        }
+
+        let (name, position) = match step.split_once('[') {
+            Some((name, position)) => {
+                let position = position.strip_suffix(']')?.parse::<usize>().ok()?;
+                if position == 0 {
+                    return None;
+                }
+                (name, Some(position))
+            }
+            None => (step, None),
+        };
+        ret.push(XmlPathStep::Element {
+            name: local_name(name),
+            position,
+            descendant,
+        });
+    }
+
+    if ret.is_empty() {
+        return None;
+    }
+    Some(ret)
+}
+
+/// name without namespace prefix
+fn local_name(name: &str) -> &str {
+    match name.split_once(':') {
+        Some((_, local_name)) => local_name,
+        None => name,
+    }
+}
+
+/// text content of element including its descendants, trimmed
+fn element_text(node: &Node) -> String {
+    node.descendants()
+        .filter(|x| x.is_text())
+        .filter_map(|x| x.text())
+        .collect::<String>()
+        .trim()
+        .to_owned()
+}
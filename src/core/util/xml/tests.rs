@@ -0,0 +1,58 @@
+use super::*;
+
+const XML: &str = r#"<?xml version="1.0"?>
+<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+  <soap:Body>
+    <GetUser id="u1">
+      <name>alice</name>
+      <tag>a</tag>
+      <tag>b</tag>
+    </GetUser>
+  </soap:Body>
+</soap:Envelope>"#;
+
+fn values(path: &str) -> Vec<String> {
+    let document = Document::parse(XML).unwrap();
+    xml_values_by_path(&document, path)
+}
+
+#[test]
+fn child_path() {
+    assert_eq!(values("/Envelope/Body/GetUser/name"), vec!["alice"]);
+    assert_eq!(
+        values("/soap:Envelope/soap:Body/GetUser/name"),
+        vec!["alice"]
+    );
+    assert_eq!(values("Envelope/Body/GetUser/name/text()"), vec!["alice"]);
+}
+
+#[test]
+fn descendant_path() {
+    assert_eq!(values("//GetUser/name"), vec!["alice"]);
+    assert_eq!(values("//tag"), vec!["a", "b"]);
+}
+
+#[test]
+fn position() {
+    assert_eq!(values("//GetUser/tag[2]"), vec!["b"]);
+    assert!(values("//GetUser/tag[3]").is_empty());
+}
+
+#[test]
+fn attribute() {
+    assert_eq!(values("//GetUser/@id"), vec!["u1"]);
+    assert!(values("//GetUser/@missing").is_empty());
+}
+
+#[test]
+fn wild_card() {
+    assert_eq!(values("/Envelope/Body/*/name"), vec!["alice"]);
+}
+
+#[test]
+fn missing_or_invalid() {
+    assert!(values("/Envelope/Header").is_empty());
+    assert!(values("/Envelope/Body/GetUser/tag[0]").is_empty());
+    assert!(values("//@id/name").is_empty());
+    assert!(values("").is_empty());
+}
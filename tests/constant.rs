@@ -5,15 +5,28 @@ pub const DYN_ROUTE_DIR: &str = "apimock-dyn-route";
 pub const DUMMY_BINARY_DATA: &[u8] = b"Q\xb0\xd6wE\xc6\xbc\xaa\x1a\x01\xbf\x9e\xb0\xf6\xac\xcd-\xe8\x8dDdummy\x97\x8d%.2\x10v)\xb5\xc6\x0b\x01\xcd\xdc4\xb9O%u\x8d";
 
 pub mod root_config_dir {
+    pub const CACHE_RESPONSE: &str = "apimock-rule-sets/server/response/cache_response";
+    pub const COMPRESSED_RESPONSE: &str = "apimock-rule-sets/server/response/compressed_response";
     pub const ERROR_RESPONSE: &str = "apimock-rule-sets/server/response/error_response";
     pub const FILE_RESPONSE: &str = "apimock-rule-sets/server/response/file_response";
+    pub const THROTTLED_RESPONSE: &str = "apimock-rule-sets/server/response/throttled_response";
     pub const RULE_SET_PREFIX: &str = "apimock-rule-sets/server/routing/rule_set/prefix";
+    pub const RULE_WHEN_COMBINATOR: &str =
+        "apimock-rule-sets/server/routing/rule_set/rule/when/combinator";
     pub const RULE_WHEN_REQUEST_URL_PATH: &str =
         "apimock-rule-sets/server/routing/rule_set/rule/when/request/url_path";
     pub const RULE_WHEN_REQUEST_HTTP_METHOD: &str =
         "apimock-rule-sets/server/routing/rule_set/rule/when/request/http_method";
+    pub const RULE_WHEN_REQUEST_QUERY: &str =
+        "apimock-rule-sets/server/routing/rule_set/rule/when/request/query";
     pub const RULE_WHEN_REQUEST_HEADERS: &str =
         "apimock-rule-sets/server/routing/rule_set/rule/when/request/headers";
+    pub const RULE_WHEN_REQUEST_COOKIES: &str =
+        "apimock-rule-sets/server/routing/rule_set/rule/when/request/cookies";
+    pub const RULE_WHEN_REQUEST_CLIENT_IP: &str =
+        "apimock-rule-sets/server/routing/rule_set/rule/when/request/client_ip";
+    pub const RULE_WHEN_REQUEST_HOST: &str =
+        "apimock-rule-sets/server/routing/rule_set/rule/when/request/host";
     pub const RULE_WHEN_REQUEST_BODY: &str =
         "apimock-rule-sets/server/routing/rule_set/rule/when/request/body";
     pub const RULE_WHEN_REQUEST_RULE_OP: &str =
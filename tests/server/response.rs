@@ -1,4 +1,10 @@
+#[path = "response/cache_response.rs"]
+mod cache_response;
+#[path = "response/compressed_response.rs"]
+mod compressed_response;
 #[path = "response/error_response.rs"]
 mod error_response;
 #[path = "response/file_response.rs"]
 mod file_response;
+#[path = "response/throttled_response.rs"]
+mod throttled_response;
@@ -0,0 +1,151 @@
+use http_body_util::BodyExt;
+use hyper::{
+    header::{HeaderValue, CACHE_CONTROL, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+    HeaderMap, StatusCode,
+};
+
+use crate::{
+    constant::root_config_dir,
+    util::{
+        http::{test_request::TestRequest, test_response::response_body_str},
+        test_setup::TestSetup,
+    },
+};
+
+#[tokio::test]
+async fn cache_validators_of_file() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/cache/file", port).send().await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get(CACHE_CONTROL).unwrap(), "max-age=60");
+    assert!(response.headers().contains_key(ETAG));
+    assert!(response.headers().contains_key(LAST_MODIFIED));
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "{\"hello\":\"cache\"}");
+}
+
+#[tokio::test]
+async fn cache_if_none_match() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/cache/file", port).send().await;
+    let etag = response.headers().get(ETAG).unwrap().to_owned();
+
+    let mut headers = HeaderMap::new();
+    headers.insert(IF_NONE_MATCH, etag.clone());
+    let response = TestRequest::default("/cache/file", port)
+        .with_headers(&headers)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers().get(ETAG).unwrap(), etag);
+    assert_eq!(response_body_str(response).await.as_str(), "");
+
+    let mut headers = HeaderMap::new();
+    headers.insert(IF_NONE_MATCH, HeaderValue::from_static("\"other\""));
+    let response = TestRequest::default("/cache/file", port)
+        .with_headers(&headers)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn cache_if_modified_since() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/cache/file", port).send().await;
+    let last_modified = response.headers().get(LAST_MODIFIED).unwrap().to_owned();
+
+    let mut headers = HeaderMap::new();
+    headers.insert(IF_MODIFIED_SINCE, last_modified);
+    let response = TestRequest::default("/cache/file", port)
+        .with_headers(&headers)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        IF_MODIFIED_SINCE,
+        HeaderValue::from_static("Thu, 01 Jan 1970 00:00:00 GMT"),
+    );
+    let response = TestRequest::default("/cache/file", port)
+        .with_headers(&headers)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn cache_validators_of_dyn_route() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/binary/video/video.mp4", port)
+        .send()
+        .await;
+    let etag = response.headers().get(ETAG).unwrap().to_owned();
+
+    let mut headers = HeaderMap::new();
+    headers.insert(IF_NONE_MATCH, etag);
+    let response = TestRequest::default("/binary/video/video.mp4", port)
+        .with_headers(&headers)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+}
+
+#[tokio::test]
+async fn cache_no_validators_of_text() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/cache/text", port).send().await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get(CACHE_CONTROL).unwrap(), "max-age=60");
+    assert!(!response.headers().contains_key(ETAG));
+    assert!(!response.headers().contains_key(LAST_MODIFIED));
+}
+
+#[tokio::test]
+async fn cache_control_of_rule_prior_to_service() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/cache/custom", port).send().await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get(CACHE_CONTROL).unwrap(), "no-cache");
+}
+
+#[tokio::test]
+async fn cache_no_validators_of_fault() {
+    let port = setup().await;
+
+    let mut headers = HeaderMap::new();
+    headers.insert(IF_NONE_MATCH, HeaderValue::from_static("*"));
+    let response = TestRequest::default("/cache/fault", port)
+        .with_headers(&headers)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(!response.headers().contains_key(ETAG));
+
+    let body = response.into_body().collect().await;
+    assert!(body.is_err());
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup = TestSetup::default_with_root_config_dir(root_config_dir::CACHE_RESPONSE);
+    let port = test_setup.launch().await;
+    port
+}
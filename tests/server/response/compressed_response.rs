@@ -0,0 +1,183 @@
+use flate2::read::{GzDecoder, ZlibDecoder};
+use http_body_util::BodyExt;
+use hyper::{
+    header::{HeaderValue, ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_TYPE},
+    HeaderMap, StatusCode,
+};
+
+use std::io::Read;
+
+use crate::{
+    constant::root_config_dir,
+    util::{
+        http::{
+            test_request::TestRequest,
+            test_response::{response_body_bytes, response_body_str},
+        },
+        test_setup::TestSetup,
+    },
+};
+
+const TEXT: &str = "0123456789012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789";
+
+#[tokio::test]
+async fn compressed_gzip() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/compress/service", port)
+        .with_headers(&accept_encoding_headers("gzip"))
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get(CONTENT_ENCODING).unwrap(), "gzip");
+    let content_length = response.headers().get(CONTENT_LENGTH).unwrap().to_owned();
+
+    let body = response_body_bytes(response).await;
+    assert_eq!(content_length, body.len().to_string().as_str());
+    assert!(body.len() < TEXT.len());
+
+    let mut decoded = String::new();
+    GzDecoder::new(body.as_ref())
+        .read_to_string(&mut decoded)
+        .unwrap();
+    assert_eq!(decoded.as_str(), TEXT);
+}
+
+#[tokio::test]
+async fn compressed_deflate() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/compress/service", port)
+        .with_headers(&accept_encoding_headers("deflate"))
+        .send()
+        .await;
+
+    assert_eq!(response.headers().get(CONTENT_ENCODING).unwrap(), "deflate");
+
+    let body = response_body_bytes(response).await;
+    let mut decoded = String::new();
+    ZlibDecoder::new(body.as_ref())
+        .read_to_string(&mut decoded)
+        .unwrap();
+    assert_eq!(decoded.as_str(), TEXT);
+}
+
+#[tokio::test]
+async fn compressed_br() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/compress/service", port)
+        .with_headers(&accept_encoding_headers("gzip, deflate, br"))
+        .send()
+        .await;
+
+    assert_eq!(response.headers().get(CONTENT_ENCODING).unwrap(), "br");
+
+    let body = response_body_bytes(response).await;
+    let mut decoded = String::new();
+    brotli::Decompressor::new(body.as_ref(), 4096)
+        .read_to_string(&mut decoded)
+        .unwrap();
+    assert_eq!(decoded.as_str(), TEXT);
+}
+
+#[tokio::test]
+async fn compressed_stream() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/compress/stream", port)
+        .with_headers(&accept_encoding_headers("gzip"))
+        .send()
+        .await;
+
+    assert_eq!(response.headers().get(CONTENT_ENCODING).unwrap(), "gzip");
+    assert!(!response.headers().contains_key(CONTENT_LENGTH));
+
+    let body = response_body_bytes(response).await;
+    let mut decoded = String::new();
+    GzDecoder::new(body.as_ref())
+        .read_to_string(&mut decoded)
+        .unwrap();
+    assert_eq!(decoded.as_str(), TEXT);
+}
+
+#[tokio::test]
+async fn uncompressed_without_accept_encoding() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/compress/service", port).send().await;
+
+    assert!(!response.headers().contains_key(CONTENT_ENCODING));
+    assert_eq!(response_body_str(response).await.as_str(), TEXT);
+}
+
+#[tokio::test]
+async fn uncompressed_by_rule() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/compress/off", port)
+        .with_headers(&accept_encoding_headers("gzip"))
+        .send()
+        .await;
+
+    assert!(!response.headers().contains_key(CONTENT_ENCODING));
+    assert_eq!(response_body_str(response).await.as_str(), TEXT);
+}
+
+#[tokio::test]
+async fn uncompressed_fault_truncate() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/compress/fault", port)
+        .with_headers(&accept_encoding_headers("gzip"))
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(!response.headers().contains_key(CONTENT_ENCODING));
+    assert_eq!(
+        response.headers().get(CONTENT_LENGTH).unwrap(),
+        TEXT.len().to_string().as_str()
+    );
+
+    let body = response.into_body().collect().await;
+    assert!(body.is_err());
+}
+
+#[tokio::test]
+async fn precompressed_file_as_is() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/compress/precompressed", port)
+        .with_headers(&accept_encoding_headers("br"))
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get(CONTENT_ENCODING).unwrap(), "gzip");
+    assert_eq!(
+        response.headers().get(CONTENT_TYPE).unwrap(),
+        "application/json"
+    );
+
+    let body = response_body_bytes(response).await;
+    let mut decoded = String::new();
+    GzDecoder::new(body.as_ref())
+        .read_to_string(&mut decoded)
+        .unwrap();
+    assert_eq!(decoded.as_str(), "{\"hello\":\"gzip\"}");
+}
+
+fn accept_encoding_headers(accept_encoding: &'static str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(ACCEPT_ENCODING, HeaderValue::from_static(accept_encoding));
+    headers
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup = TestSetup::default_with_root_config_dir(root_config_dir::COMPRESSED_RESPONSE);
+    let port = test_setup.launch().await;
+    port
+}
@@ -0,0 +1,47 @@
+use hyper::{header::CONTENT_LENGTH, StatusCode};
+
+use std::time::{Duration, Instant};
+
+use crate::{
+    constant::root_config_dir,
+    util::{
+        http::{test_request::TestRequest, test_response::response_body_str},
+        test_setup::TestSetup,
+    },
+};
+
+#[tokio::test]
+async fn throttled_by_service() {
+    let port = setup().await;
+
+    let started_at = Instant::now();
+    let response = TestRequest::default("/throttle/service", port).send().await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get(CONTENT_LENGTH).unwrap(), "300");
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.len(), 300);
+    assert!(Duration::from_millis(150) <= started_at.elapsed());
+}
+
+#[tokio::test]
+async fn throttled_by_rule_prior_to_service() {
+    let port = setup().await;
+
+    let started_at = Instant::now();
+    let response = TestRequest::default("/throttle/rule", port).send().await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.len(), 300);
+    assert!(started_at.elapsed() < Duration::from_millis(150));
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup = TestSetup::default_with_root_config_dir(root_config_dir::THROTTLED_RESPONSE);
+    let port = test_setup.launch().await;
+    port
+}
@@ -6,5 +6,7 @@ mod audio;
 mod data;
 #[path = "binary/image.rs"]
 mod image;
+#[path = "binary/range.rs"]
+mod range;
 #[path = "binary/video.rs"]
 mod video;
@@ -0,0 +1,125 @@
+use hyper::{
+    header::{HeaderValue, ACCEPT_RANGES, CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE, RANGE},
+    HeaderMap, StatusCode,
+};
+
+use crate::{
+    constant::DUMMY_BINARY_DATA,
+    util::{
+        http::{test_request::TestRequest, test_response::response_body_bytes},
+        test_setup::TestSetup,
+    },
+};
+
+#[tokio::test]
+async fn dyn_binary_accept_ranges() {
+    let port = TestSetup::default().launch().await;
+
+    let response = TestRequest::default("/binary/video/video.mp4", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get(ACCEPT_RANGES).unwrap(), "bytes");
+}
+
+#[tokio::test]
+async fn dyn_binary_single_range() {
+    let port = TestSetup::default().launch().await;
+
+    let response = TestRequest::default("/binary/video/video.mp4", port)
+        .with_headers(&range_headers("bytes=0-9"))
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(response.headers().get(CONTENT_TYPE).unwrap(), "video/mp4");
+    assert_eq!(response.headers().get(CONTENT_LENGTH).unwrap(), "10");
+    assert_eq!(
+        response.headers().get(CONTENT_RANGE).unwrap(),
+        format!("bytes 0-9/{}", DUMMY_BINARY_DATA.len()).as_str()
+    );
+
+    let body = response_body_bytes(response).await;
+    assert_eq!(body.as_ref(), &DUMMY_BINARY_DATA[..10]);
+}
+
+#[tokio::test]
+async fn dyn_binary_suffix_range() {
+    let port = TestSetup::default().launch().await;
+
+    let response = TestRequest::default("/binary/audio/sound.mp3", port)
+        .with_headers(&range_headers("bytes=-5"))
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+
+    let body = response_body_bytes(response).await;
+    assert_eq!(
+        body.as_ref(),
+        &DUMMY_BINARY_DATA[DUMMY_BINARY_DATA.len() - 5..]
+    );
+}
+
+#[tokio::test]
+async fn dyn_binary_multi_range() {
+    let port = TestSetup::default().launch().await;
+
+    let response = TestRequest::default("/binary/video/video.mp4", port)
+        .with_headers(&range_headers("bytes=0-1,4-5"))
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_owned();
+    let boundary = content_type
+        .strip_prefix("multipart/byteranges; boundary=")
+        .unwrap();
+
+    let total = DUMMY_BINARY_DATA.len();
+    let mut expected = vec![];
+    for (start, end) in [(0, 1), (4, 5)] {
+        expected.extend_from_slice(
+            format!(
+                "--{}\r\ncontent-type: video/mp4\r\ncontent-range: bytes {}-{}/{}\r\n\r\n",
+                boundary, start, end, total
+            )
+            .as_bytes(),
+        );
+        expected.extend_from_slice(&DUMMY_BINARY_DATA[start..=end]);
+        expected.extend_from_slice(b"\r\n");
+    }
+    expected.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+
+    let body = response_body_bytes(response).await;
+    assert_eq!(body.as_ref(), expected.as_slice());
+}
+
+#[tokio::test]
+async fn dyn_binary_range_not_satisfiable() {
+    let port = TestSetup::default().launch().await;
+
+    let response = TestRequest::default("/binary/video/video.mp4", port)
+        .with_headers(&range_headers("bytes=1000-"))
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+    assert_eq!(
+        response.headers().get(CONTENT_RANGE).unwrap(),
+        format!("bytes */{}", DUMMY_BINARY_DATA.len()).as_str()
+    );
+}
+
+fn range_headers(range: &'static str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(RANGE, HeaderValue::from_static(range));
+    headers
+}
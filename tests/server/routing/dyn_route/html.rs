@@ -1,7 +1,10 @@
 use hyper::StatusCode;
 
 use crate::util::{
-    http::{test_request::TestRequest, test_response::response_body_str},
+    http::{
+        test_request::TestRequest,
+        test_response::{response_body_bytes, response_body_str},
+    },
     test_setup::TestSetup,
 };
 
@@ -64,3 +67,20 @@ async fn match_dyn_data_dir_html_3() {
         "<!DOCTYPE html>\nHello from API mock (apimock-rs)"
     );
 }
+
+#[tokio::test]
+async fn match_dyn_data_dir_html_not_utf8() {
+    let port = TestSetup::default().launch().await;
+
+    let response = TestRequest::default("/html/latin1.html", port).send().await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "text/html; charset=utf-8"
+    );
+
+    let body = response_body_bytes(response).await;
+    assert_eq!(body.as_ref(), b"<!DOCTYPE html>\n<p>caf\xe9</p>\n");
+}
@@ -0,0 +1,27 @@
+use hyper::{header::CONTENT_TYPE, StatusCode};
+
+use crate::util::{
+    http::{test_request::TestRequest, test_response::response_body_str},
+    test_setup::TestSetup,
+};
+
+#[tokio::test]
+async fn matches_dyn_route_jsonl() {
+    let port = TestSetup::default().launch().await;
+
+    let response = TestRequest::default("/ndjson/logs.jsonl", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get(CONTENT_TYPE).unwrap(),
+        "application/x-ndjson"
+    );
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(
+        body_str.as_str(),
+        "{\"level\":\"info\",\"message\":\"started\"}\n{\"level\":\"warn\",\"message\":\"slow\"}\n"
+    );
+}
@@ -1,2 +1,26 @@
+#[path = "respond/compression.rs"]
+mod compression;
 #[path = "respond/csv.rs"]
 mod csv;
+#[path = "respond/delay.rs"]
+mod delay;
+#[path = "respond/fault.rs"]
+mod fault;
+#[path = "respond/json.rs"]
+mod json;
+#[path = "respond/ndjson.rs"]
+mod ndjson;
+#[path = "respond/one_of.rs"]
+mod one_of;
+#[path = "respond/range.rs"]
+mod range;
+#[path = "respond/sequence.rs"]
+mod sequence;
+#[path = "respond/sse.rs"]
+mod sse;
+#[path = "respond/status.rs"]
+mod status;
+#[path = "respond/template.rs"]
+mod template;
+#[path = "respond/websocket.rs"]
+mod websocket;
@@ -0,0 +1,44 @@
+use flate2::read::GzDecoder;
+use hyper::{
+    header::{HeaderValue, ACCEPT_ENCODING, CONTENT_ENCODING},
+    HeaderMap, StatusCode,
+};
+
+use std::io::Read;
+
+use crate::{
+    constant::root_config_dir,
+    util::{
+        http::{test_request::TestRequest, test_response::response_body_bytes},
+        test_setup::TestSetup,
+    },
+};
+
+#[tokio::test]
+async fn compression_by_rule() {
+    let port = setup().await;
+
+    let mut headers = HeaderMap::new();
+    headers.insert(ACCEPT_ENCODING, HeaderValue::from_static("gzip"));
+    let response = TestRequest::default("/respond/compression", port)
+        .with_headers(&headers)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get(CONTENT_ENCODING).unwrap(), "gzip");
+
+    let body = response_body_bytes(response).await;
+    let mut decoded = String::new();
+    GzDecoder::new(body.as_ref())
+        .read_to_string(&mut decoded)
+        .unwrap();
+    assert_eq!(decoded.as_str(), "{\"message\":\"compressed by rule\"}");
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup = TestSetup::default_with_root_config_dir(root_config_dir::RULE_RESPOND);
+    let port = test_setup.launch().await;
+    port
+}
@@ -0,0 +1,63 @@
+use hyper::StatusCode;
+
+use std::time::{Duration, Instant};
+
+use crate::{
+    constant::root_config_dir,
+    util::{
+        http::{test_request::TestRequest, test_response::response_body_str},
+        test_setup::TestSetup,
+    },
+};
+
+#[tokio::test]
+async fn delay_default_of_rule_set() {
+    let port = setup().await;
+
+    let started_at = Instant::now();
+    let response = TestRequest::default("/delay/default", port).send().await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(Duration::from_millis(300) <= started_at.elapsed());
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "default");
+}
+
+#[tokio::test]
+async fn delay_own_overrides_default() {
+    let port = setup().await;
+
+    let started_at = Instant::now();
+    let response = TestRequest::default("/delay/own", port).send().await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(started_at.elapsed() < Duration::from_millis(300));
+}
+
+#[tokio::test]
+async fn delay_uniform_range() {
+    let port = setup().await;
+
+    let started_at = Instant::now();
+    let response = TestRequest::default("/delay/uniform", port).send().await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let elapsed = started_at.elapsed();
+    assert!(Duration::from_millis(100) <= elapsed);
+    assert!(elapsed < Duration::from_millis(300));
+}
+
+#[tokio::test]
+async fn delay_log_normal_clamped() {
+    let port = setup().await;
+
+    let started_at = Instant::now();
+    let response = TestRequest::default("/delay/log_normal", port).send().await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(started_at.elapsed() < Duration::from_millis(300));
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup = TestSetup::default_with_root_config_dir(root_config_dir::RULE_RESPOND);
+    let port = test_setup.launch().await;
+    port
+}
@@ -0,0 +1,77 @@
+use http_body_util::BodyExt;
+use hyper::{header::CONTENT_LENGTH, StatusCode};
+
+use std::time::Duration;
+
+use crate::{
+    constant::root_config_dir,
+    util::{http::test_request::TestRequest, test_setup::TestSetup},
+};
+
+#[tokio::test]
+async fn fault_close() {
+    let port = setup().await;
+    let response = TestRequest::default("/respond/fault/close", port)
+        .try_send()
+        .await;
+
+    assert!(response.is_err());
+}
+
+#[tokio::test]
+async fn fault_reset() {
+    let port = setup().await;
+    let response = TestRequest::default("/respond/fault/reset", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get(CONTENT_LENGTH).is_none());
+
+    let body = response.into_body().collect().await;
+    assert!(body.is_err());
+}
+
+#[tokio::test]
+async fn fault_truncate() {
+    let port = setup().await;
+    let response = TestRequest::default("/respond/fault/truncate", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get(CONTENT_LENGTH).unwrap(), "20");
+
+    let mut body = response.into_body();
+    let mut received = Vec::new();
+    let err = loop {
+        match body.frame().await {
+            Some(Ok(frame)) => received.extend_from_slice(&frame.into_data().unwrap()),
+            Some(Err(err)) => break Some(err),
+            None => break None,
+        }
+    };
+    assert!(err.is_some());
+    assert_eq!(received, b"0123456789");
+}
+
+#[tokio::test]
+async fn fault_stall() {
+    let port = setup().await;
+    let response = TestRequest::default("/respond/fault/stall", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body =
+        tokio::time::timeout(Duration::from_millis(300), response.into_body().collect()).await;
+    assert!(body.is_err());
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup = TestSetup::default_with_root_config_dir(root_config_dir::RULE_RESPOND);
+    let port = test_setup.launch().await;
+    port
+}
@@ -0,0 +1,72 @@
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::{
+    constant::root_config_dir,
+    util::{
+        http::{test_request::TestRequest, test_response::response_body_str},
+        test_setup::TestSetup,
+    },
+};
+
+#[tokio::test]
+async fn json_object() {
+    let port = setup().await;
+    let response = TestRequest::default("/respond/json/object", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/json"
+    );
+
+    let body_str = response_body_str(response).await;
+    let body = serde_json::from_str::<serde_json::Value>(body_str.as_str()).unwrap();
+    assert_eq!(
+        body,
+        json!({"id": 1, "name": "alice", "tags": ["a", "b"], "profile": {"active": true}})
+    );
+}
+
+#[tokio::test]
+async fn json_array_with_status_and_headers() {
+    let port = setup().await;
+    let response = TestRequest::default("/respond/json/array", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/json"
+    );
+    assert_eq!(response.headers().get("x-total-count").unwrap(), "2");
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), json!([{"id": 1}, {"id": 2}]).to_string());
+}
+
+#[tokio::test]
+async fn json_template() {
+    let port = setup().await;
+    let response = TestRequest::default("/respond/json/template?page=3", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    let body = serde_json::from_str::<serde_json::Value>(body_str.as_str()).unwrap();
+    assert_eq!(body, json!({"page": "3", "method": "GET"}));
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup = TestSetup::default_with_root_config_dir(root_config_dir::RULE_RESPOND);
+    let port = test_setup.launch().await;
+    port
+}
@@ -0,0 +1,78 @@
+use http_body_util::BodyExt;
+use hyper::{
+    header::{CONTENT_LENGTH, CONTENT_TYPE, TRANSFER_ENCODING},
+    StatusCode,
+};
+
+use std::time::{Duration, Instant};
+
+use crate::{
+    constant::root_config_dir,
+    util::{
+        http::{test_request::TestRequest, test_response::response_body_str},
+        test_setup::TestSetup,
+    },
+};
+
+#[tokio::test]
+async fn ndjson_file_streamed_at_interval() {
+    let port = setup().await;
+
+    let started_at = Instant::now();
+    let response = TestRequest::default("/respond/ndjson/file", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get(CONTENT_TYPE).unwrap(),
+        "application/x-ndjson"
+    );
+    assert_eq!(
+        response.headers().get(TRANSFER_ENCODING).unwrap(),
+        "chunked"
+    );
+    assert!(!response.headers().contains_key(CONTENT_LENGTH));
+
+    let mut body = response.into_body();
+    let mut records = vec![];
+    while let Some(frame) = body.frame().await {
+        if let Ok(data) = frame.unwrap().into_data() {
+            records.push(String::from_utf8(data.to_vec()).unwrap());
+        }
+    }
+
+    assert_eq!(
+        records.concat(),
+        "{\"id\":1,\"name\":\"alice\"}\n{\"id\":2,\"name\":\"bob\"}\n{\"id\":3,\"name\":\"carol\"}\n"
+    );
+    assert!(Duration::from_millis(100) <= started_at.elapsed());
+}
+
+#[tokio::test]
+async fn ndjson_csv_rows() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/respond/ndjson/csv", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get(CONTENT_TYPE).unwrap(),
+        "application/x-ndjson"
+    );
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(
+        body_str.as_str(),
+        "{\"fieldA\":\"1\",\"fieldB\":\"2\",\"fieldC\":\"3\"}\n{\"fieldA\":\"a\",\"fieldB\":\"b\",\"fieldC\":\"c\"}\n{\"fieldA\":\"#\",\"fieldB\":\"\\\\,\",\"fieldC\":\"!!!\"}\n"
+    );
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup = TestSetup::default_with_root_config_dir(root_config_dir::RULE_RESPOND);
+    let port = test_setup.launch().await;
+    port
+}
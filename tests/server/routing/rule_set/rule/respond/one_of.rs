@@ -0,0 +1,52 @@
+use hyper::StatusCode;
+
+use crate::{
+    constant::root_config_dir,
+    util::{
+        http::{test_request::TestRequest, test_response::response_body_str},
+        test_setup::TestSetup,
+    },
+};
+
+#[tokio::test]
+async fn one_of_seeded_is_reproducible() {
+    let statuses = |port: u16| async move {
+        let mut ret = vec![];
+        for _ in 0..30 {
+            let response = TestRequest::default("/respond/one_of/flaky", port)
+                .send()
+                .await;
+            ret.push(response.status());
+        }
+        ret
+    };
+
+    let first = statuses(setup().await).await;
+    let second = statuses(setup().await).await;
+    assert_eq!(first, second);
+    assert!(first
+        .iter()
+        .all(|x| *x == StatusCode::OK || *x == StatusCode::SERVICE_UNAVAILABLE));
+    assert!(first.contains(&StatusCode::OK));
+}
+
+#[tokio::test]
+async fn one_of_zero_weight() {
+    let port = setup().await;
+
+    for _ in 0..10 {
+        let response = TestRequest::default("/respond/one_of/disabled", port)
+            .send()
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body_str = response_body_str(response).await;
+        assert_eq!(body_str.as_str(), "ok");
+    }
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup = TestSetup::default_with_root_config_dir(root_config_dir::RULE_RESPOND);
+    let port = test_setup.launch().await;
+    port
+}
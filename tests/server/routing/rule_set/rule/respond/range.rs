@@ -0,0 +1,75 @@
+use hyper::{
+    header::{HeaderValue, CONTENT_RANGE, CONTENT_TYPE, RANGE},
+    HeaderMap, StatusCode,
+};
+
+use crate::{
+    constant::{root_config_dir, DUMMY_BINARY_DATA},
+    util::{
+        http::{test_request::TestRequest, test_response::response_body_bytes},
+        test_setup::TestSetup,
+    },
+};
+
+#[tokio::test]
+async fn range_of_file_path() {
+    let port = setup().await;
+
+    let mut headers = HeaderMap::new();
+    headers.insert(RANGE, HeaderValue::from_static("bytes=10-"));
+    let response = TestRequest::default("/respond/range/media", port)
+        .with_headers(&headers)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(
+        response.headers().get(CONTENT_RANGE).unwrap(),
+        format!(
+            "bytes 10-{}/{}",
+            DUMMY_BINARY_DATA.len() - 1,
+            DUMMY_BINARY_DATA.len()
+        )
+        .as_str()
+    );
+
+    let body = response_body_bytes(response).await;
+    assert_eq!(body.as_ref(), &DUMMY_BINARY_DATA[10..]);
+}
+
+#[tokio::test]
+async fn custom_content_type_of_file_path() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/respond/range/media-custom-content-type", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get(CONTENT_TYPE).unwrap(), "video/webm");
+
+    let body = response_body_bytes(response).await;
+    assert_eq!(body.as_ref(), DUMMY_BINARY_DATA);
+}
+
+#[tokio::test]
+async fn custom_content_type_of_range() {
+    let port = setup().await;
+
+    let mut headers = HeaderMap::new();
+    headers.insert(RANGE, HeaderValue::from_static("bytes=0-9"));
+    let response = TestRequest::default("/respond/range/media-custom-content-type", port)
+        .with_headers(&headers)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(response.headers().get(CONTENT_TYPE).unwrap(), "video/webm");
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup = TestSetup::default_with_root_config_dir(root_config_dir::RULE_RESPOND);
+    let port = test_setup.launch().await;
+    port
+}
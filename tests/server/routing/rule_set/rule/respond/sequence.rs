@@ -0,0 +1,78 @@
+use hyper::{Method, StatusCode};
+use serde_json::json;
+
+use crate::{
+    constant::root_config_dir,
+    util::{
+        http::{test_request::TestRequest, test_response::response_body_str},
+        test_setup::TestSetup,
+    },
+};
+
+#[tokio::test]
+async fn sequence_stick_to_last() {
+    let port = setup().await;
+
+    for _ in 0..2 {
+        let response = TestRequest::default("/respond/sequence/job", port)
+            .send()
+            .await;
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+        let body_str = response_body_str(response).await;
+        assert_eq!(body_str.as_str(), json!({"status": "pending"}).to_string());
+    }
+
+    for _ in 0..2 {
+        let response = TestRequest::default("/respond/sequence/job", port)
+            .send()
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body_str = response_body_str(response).await;
+        assert_eq!(body_str.as_str(), json!({"status": "done"}).to_string());
+    }
+}
+
+#[tokio::test]
+async fn sequence_loop() {
+    let port = setup().await;
+
+    let statuses = [
+        StatusCode::SERVICE_UNAVAILABLE,
+        StatusCode::OK,
+        StatusCode::SERVICE_UNAVAILABLE,
+    ];
+    for status in statuses {
+        let response = TestRequest::default("/respond/sequence/retry", port)
+            .send()
+            .await;
+        assert_eq!(response.status(), status);
+    }
+}
+
+#[tokio::test]
+async fn sequence_reset() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/respond/sequence/retry", port)
+        .send()
+        .await;
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+    let response = TestRequest::default("/__apimock/sequence/reset", port)
+        .with_http_method(&Method::POST)
+        .send()
+        .await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = TestRequest::default("/respond/sequence/retry", port)
+        .send()
+        .await;
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup = TestSetup::default_with_root_config_dir(root_config_dir::RULE_RESPOND);
+    let port = test_setup.launch().await;
+    port
+}
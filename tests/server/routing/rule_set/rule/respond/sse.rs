@@ -0,0 +1,101 @@
+use http_body_util::BodyExt;
+use hyper::{
+    body::Incoming,
+    header::{CONTENT_LENGTH, CONTENT_TYPE},
+    Response, StatusCode,
+};
+
+use std::time::{Duration, Instant};
+
+use crate::{
+    constant::root_config_dir,
+    util::{
+        http::{test_request::TestRequest, test_response::response_body_str},
+        test_setup::TestSetup,
+    },
+};
+
+#[tokio::test]
+async fn sse_events_in_rule() {
+    let port = setup().await;
+
+    let started_at = Instant::now();
+    let response = TestRequest::default("/respond/sse/list", port).send().await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get(CONTENT_TYPE).unwrap(),
+        "text/event-stream"
+    );
+    assert_eq!(response.headers().get("cache-control").unwrap(), "no-cache");
+    assert!(!response.headers().contains_key(CONTENT_LENGTH));
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(
+        body_str.as_str(),
+        "id: 1\nevent: greeting\nretry: 3000\ndata: hello\n\nid: 2\ndata: {\"count\":2}\n\n"
+    );
+    assert!(Duration::from_millis(100) <= started_at.elapsed());
+}
+
+#[tokio::test]
+async fn sse_events_from_file() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/respond/sse/file", port).send().await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(
+        body_str.as_str(),
+        "id: 1\nevent: update\ndata: first\n\nid: 2\nevent: update\ndata: second\n\n"
+    );
+}
+
+#[tokio::test]
+async fn sse_keep_open() {
+    let port = setup().await;
+
+    let mut response = TestRequest::default("/respond/sse/keep-open", port)
+        .send()
+        .await;
+
+    assert_eq!(
+        next_event(&mut response).await.unwrap().as_str(),
+        "data: only once\n\n"
+    );
+    assert!(next_event(&mut response).await.is_none());
+}
+
+#[tokio::test]
+async fn sse_repeat() {
+    let port = setup().await;
+
+    let mut response = TestRequest::default("/respond/sse/repeat", port)
+        .send()
+        .await;
+
+    for _ in 0..3 {
+        assert_eq!(
+            next_event(&mut response).await.unwrap().as_str(),
+            "data: tick\n\n"
+        );
+    }
+}
+
+/// next event frame unless nothing arrives for a while
+async fn next_event(response: &mut Response<Incoming>) -> Option<String> {
+    let frame = tokio::time::timeout(Duration::from_millis(300), response.body_mut().frame())
+        .await
+        .ok()??
+        .unwrap();
+    Some(String::from_utf8(frame.into_data().unwrap().to_vec()).unwrap())
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup = TestSetup::default_with_root_config_dir(root_config_dir::RULE_RESPOND);
+    let port = test_setup.launch().await;
+    port
+}
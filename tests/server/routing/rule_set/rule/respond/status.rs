@@ -0,0 +1,56 @@
+use hyper::StatusCode;
+use serde_json::json;
+
+use crate::{
+    constant::root_config_dir,
+    util::{
+        http::{test_request::TestRequest, test_response::response_body_str},
+        test_setup::TestSetup,
+    },
+};
+
+#[tokio::test]
+async fn status_with_json_file() {
+    let port = setup().await;
+    let response = TestRequest::default("/respond/status/json", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/json"
+    );
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(
+        body_str.as_str(),
+        json!({"error": "invalid request"}).to_string()
+    );
+}
+
+#[tokio::test]
+async fn status_with_csv_file() {
+    let port = setup().await;
+    let response = TestRequest::default("/respond/status/csv", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/json"
+    );
+
+    let body_str = response_body_str(response).await;
+    assert!(body_str.starts_with("{\"records\":["));
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup = TestSetup::default_with_root_config_dir(root_config_dir::RULE_RESPOND);
+    let port = test_setup.launch().await;
+    port
+}
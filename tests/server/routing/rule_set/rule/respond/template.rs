@@ -0,0 +1,110 @@
+use hyper::{
+    header::{HeaderName, HeaderValue},
+    HeaderMap, StatusCode,
+};
+use serde_json::{json, Value};
+
+use crate::{
+    constant::root_config_dir,
+    util::{
+        http::{test_request::TestRequest, test_response::response_body_str},
+        test_setup::TestSetup,
+    },
+};
+
+#[tokio::test]
+async fn template_json_file() {
+    let port = setup().await;
+
+    let mut headers: HeaderMap = [(
+        HeaderName::from_static("x-user"),
+        HeaderValue::from_static("admin"),
+    )]
+    .into_iter()
+    .collect();
+    headers.insert(
+        hyper::header::CONTENT_TYPE,
+        HeaderValue::from_static("application/json"),
+    );
+    let body = json!({"name": "alice", "age": 20});
+    let response = TestRequest::default("/respond/template/groups/dev/users", port)
+        .with_headers(&headers)
+        .with_body(body.to_string().as_str())
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    let mut body_json = serde_json::from_str::<Value>(body_str.as_str()).unwrap();
+    let id = body_json.as_object_mut().unwrap().remove("id").unwrap();
+    assert_eq!(id.as_str().unwrap().len(), 36);
+    assert_eq!(
+        body_json,
+        json!({"name": "alice", "age": 20, "group": "dev", "note": "created by admin"})
+    );
+}
+
+#[tokio::test]
+async fn template_text() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/respond/template/text?page=3", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "page 3 of GET");
+}
+
+#[tokio::test]
+async fn template_text_not_rendering_captures() {
+    let port = setup().await;
+
+    let headers: HeaderMap = [(
+        HeaderName::from_static("x-name"),
+        HeaderValue::from_static("{{ headers.authorization }}"),
+    )]
+    .into_iter()
+    .collect();
+    let response = TestRequest::default("/respond/template/captures", port)
+        .with_headers(&headers)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(
+        body_str.as_str(),
+        "hello {{ headers.authorization }} by GET"
+    );
+}
+
+#[tokio::test]
+async fn template_disabled() {
+    let port = setup().await;
+
+    let body = json!({"name": "alice"});
+    let response = TestRequest::default("/respond/template/disabled", port)
+        .with_body_as_json(body.to_string().as_str())
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(
+        body_str.as_str(),
+        json!({"name": "{{ body.name }}"}).to_string()
+    );
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup = TestSetup::default_with_root_config_dir(root_config_dir::RULE_RESPOND);
+    let port = test_setup.launch().await;
+    port
+}
@@ -0,0 +1,98 @@
+use futures_util::{SinkExt, StreamExt};
+use hyper::StatusCode;
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+use crate::{
+    constant::root_config_dir,
+    util::{
+        http::{test_request::TestRequest, test_response::response_body_str},
+        test_setup::TestSetup,
+    },
+};
+
+type ClientStream = WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>;
+
+#[tokio::test]
+async fn websocket_on_connect() {
+    let port = setup().await;
+
+    let mut stream = connect(port).await;
+
+    assert_eq!(next_text(&mut stream).await.as_str(), "welcome");
+    assert_eq!(
+        next_text(&mut stream).await.as_str(),
+        "{\"type\":\"ready\"}"
+    );
+}
+
+#[tokio::test]
+async fn websocket_reply_by_json_condition() {
+    let port = setup().await;
+
+    let mut stream = connect(port).await;
+    skip_on_connect(&mut stream).await;
+
+    stream
+        .send(Message::text("{\"type\":\"ping\"}"))
+        .await
+        .unwrap();
+    assert_eq!(next_text(&mut stream).await.as_str(), "{\"type\":\"pong\"}");
+
+    stream.send(Message::text("hello")).await.unwrap();
+    assert_eq!(next_text(&mut stream).await.as_str(), "unknown");
+}
+
+#[tokio::test]
+async fn websocket_reply_and_close() {
+    let port = setup().await;
+
+    let mut stream = connect(port).await;
+    skip_on_connect(&mut stream).await;
+
+    stream.send(Message::text("bye")).await.unwrap();
+    assert_eq!(next_text(&mut stream).await.as_str(), "see you");
+
+    let closed = stream.next().await;
+    assert!(matches!(closed, Some(Ok(Message::Close(_))) | None));
+}
+
+#[tokio::test]
+async fn websocket_upgrade_required() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/respond/ws/chat", port).send().await;
+
+    assert_eq!(response.status(), StatusCode::UPGRADE_REQUIRED);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "websocket handshake is required");
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup = TestSetup::default_with_root_config_dir(root_config_dir::RULE_RESPOND);
+    let port = test_setup.launch().await;
+    port
+}
+
+/// connect to websocket rule
+async fn connect(port: u16) -> ClientStream {
+    let url = format!("ws://127.0.0.1:{}/respond/ws/chat", port);
+    let (stream, response) = connect_async(url).await.unwrap();
+    assert_eq!(response.status(), StatusCode::SWITCHING_PROTOCOLS);
+    stream
+}
+
+/// text of next message
+async fn next_text(stream: &mut ClientStream) -> String {
+    match stream.next().await {
+        Some(Ok(Message::Text(x))) => x.as_str().to_owned(),
+        x => panic!("unexpected message: {:?}", x),
+    }
+}
+
+/// skip messages sent on connect
+async fn skip_on_connect(stream: &mut ClientStream) {
+    next_text(stream).await;
+    next_text(stream).await;
+}
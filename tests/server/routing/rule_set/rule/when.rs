@@ -1,2 +1,4 @@
+#[path = "when/combinator.rs"]
+mod combinator;
 #[path = "when/request.rs"]
 mod request;
@@ -0,0 +1,114 @@
+use hyper::{
+    header::{HeaderName, HeaderValue},
+    HeaderMap, Method, StatusCode,
+};
+
+use crate::{
+    constant::root_config_dir,
+    util::{
+        http::{test_request::TestRequest, test_response::response_body_str},
+        test_setup::TestSetup,
+    },
+};
+
+#[tokio::test]
+async fn matches_any_1() {
+    let port = setup().await;
+
+    for http_method in [Method::PUT, Method::PATCH] {
+        let response = TestRequest::default("/combinator/any", port)
+            .with_http_method(&http_method)
+            .send()
+            .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body_str = response_body_str(response).await;
+        assert_eq!(body_str.as_str(), "put or patch");
+    }
+}
+
+#[tokio::test]
+async fn not_matches_any_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/combinator/any", port)
+        .with_http_method(&Method::DELETE)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn matches_not_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/combinator/not", port).send().await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "no debug header");
+}
+
+#[tokio::test]
+async fn not_matches_not_1() {
+    let port = setup().await;
+
+    let headers: HeaderMap = [(
+        HeaderName::from_static("x-debug"),
+        HeaderValue::from_static("1"),
+    )]
+    .into_iter()
+    .collect();
+    let response = TestRequest::default("/combinator/not", port)
+        .with_headers(&headers)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn matches_nested_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/combinator/nested?role=owner", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "active privileged user");
+}
+
+#[tokio::test]
+async fn not_matches_nested_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/combinator/nested?role=guest", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn not_matches_nested_2() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/combinator/nested?role=admin&suspended=1", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup = TestSetup::default_with_root_config_dir(root_config_dir::RULE_WHEN_COMBINATOR);
+    let port = test_setup.launch().await;
+    port
+}
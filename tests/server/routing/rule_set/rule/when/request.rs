@@ -1,9 +1,17 @@
 #[path = "request/body.rs"]
 mod body;
+#[path = "request/client_ip.rs"]
+mod client_ip;
+#[path = "request/cookies.rs"]
+mod cookies;
 #[path = "request/headers.rs"]
 mod headers;
+#[path = "request/host.rs"]
+mod host;
 #[path = "request/http_method.rs"]
 mod http_method;
+#[path = "request/query.rs"]
+mod query;
 #[path = "request/rule_op.rs"]
 mod rule_op;
 #[path = "request/url_path.rs"]
@@ -1,4 +1,7 @@
-use hyper::StatusCode;
+use hyper::{
+    header::{HeaderValue, CONTENT_TYPE},
+    HeaderMap, StatusCode,
+};
 use serde_json::json;
 
 use crate::{
@@ -444,6 +447,241 @@ async fn not_matches_array_5() {
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
 
+#[tokio::test]
+async fn matches_form_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/body", port)
+        .with_headers(&content_type_headers("application/x-www-form-urlencoded"))
+        .with_body("user=alice&tag=a&tag=b+c")
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str, "body form matched");
+}
+
+#[tokio::test]
+async fn not_matches_form_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/body", port)
+        .with_headers(&content_type_headers("application/x-www-form-urlencoded"))
+        .with_body("user=bob&tag=b+c")
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn not_matches_form_2() {
+    let port = setup().await;
+
+    // content-type: NOT application/x-www-form-urlencoded
+    let response = TestRequest::default("/body", port)
+        .with_body("user=alice&tag=b+c")
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn matches_multipart_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/body", port)
+        .with_headers(&content_type_headers("multipart/form-data; boundary=XyZ"))
+        .with_body(multipart_body("me.png").as_str())
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str, "body multipart matched");
+}
+
+#[tokio::test]
+async fn not_matches_multipart_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/body", port)
+        .with_headers(&content_type_headers("multipart/form-data; boundary=XyZ"))
+        .with_body(multipart_body("me.jpg").as_str())
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn matches_text_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/body", port)
+        .with_headers(&content_type_headers("text/plain"))
+        .with_body("ping from client")
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str, "body text matched");
+}
+
+#[tokio::test]
+async fn not_matches_text_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/body", port)
+        .with_headers(&content_type_headers("text/plain"))
+        .with_body("pong")
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn matches_xml_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/body", port)
+        .with_headers(&content_type_headers("text/xml"))
+        .with_body(soap_body("alice").as_str())
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str, "body xml matched");
+}
+
+#[tokio::test]
+async fn not_matches_xml_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/body", port)
+        .with_headers(&content_type_headers("text/xml"))
+        .with_body(soap_body("bob").as_str())
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+/// soap xml body
+fn soap_body(name: &str) -> String {
+    format!(
+        "<soap:Envelope xmlns:soap=\"http://schemas.xmlsoap.org/soap/envelope/\">\
+        <soap:Body><GetUser id=\"u1\"><name>{}</name></GetUser></soap:Body>\
+        </soap:Envelope>",
+        name
+    )
+}
+
+#[tokio::test]
+async fn matches_jsonpath_wildcard_1() {
+    let port = setup().await;
+
+    let body = json!({"items": [{"sku": "a-1"}, {"sku": "b-2"}]});
+    let response = TestRequest::default("/body", port)
+        .with_body_as_json(body.to_string().as_str())
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str, "body jsonpath wildcard matched");
+}
+
+#[tokio::test]
+async fn not_matches_jsonpath_wildcard_1() {
+    let port = setup().await;
+
+    let body = json!({"items": [{"sku": "a-1"}, {"sku": "c-2"}]});
+    let response = TestRequest::default("/body", port)
+        .with_body_as_json(body.to_string().as_str())
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn matches_jsonpath_bracket_1() {
+    let port = setup().await;
+
+    let body = json!({"order": {"id.v1": "o-1"}});
+    let response = TestRequest::default("/body", port)
+        .with_body_as_json(body.to_string().as_str())
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str, "body jsonpath bracket matched");
+}
+
+#[tokio::test]
+async fn matches_jsonpath_filter_1() {
+    let port = setup().await;
+
+    let body = json!({"cart": {"items": [{"sku": "c-3", "price": 25}]}});
+    let response = TestRequest::default("/body", port)
+        .with_body_as_json(body.to_string().as_str())
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str, "body jsonpath filter matched");
+}
+
+#[tokio::test]
+async fn not_matches_jsonpath_filter_1() {
+    let port = setup().await;
+
+    let body = json!({"cart": {"items": [{"sku": "c-3", "price": 5}]}});
+    let response = TestRequest::default("/body", port)
+        .with_body_as_json(body.to_string().as_str())
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+/// headers with content-type
+fn content_type_headers(content_type: &'static str) -> HeaderMap {
+    [(CONTENT_TYPE, HeaderValue::from_static(content_type))]
+        .into_iter()
+        .collect()
+}
+
+/// multipart body with a text field and a file
+fn multipart_body(filename: &str) -> String {
+    format!(
+        "--XyZ\r\n\
+        Content-Disposition: form-data; name=\"title\"\r\n\r\n\
+        hello\r\n\
+        --XyZ\r\n\
+        Content-Disposition: form-data; name=\"avatar\"; filename=\"{}\"\r\n\
+        Content-Type: image/png\r\n\r\n\
+        dummy\r\n\
+        --XyZ--\r\n",
+        filename
+    )
+}
+
 /// internal setup fn
 async fn setup() -> u16 {
     let test_setup =
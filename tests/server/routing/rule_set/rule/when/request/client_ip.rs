@@ -0,0 +1,52 @@
+use hyper::StatusCode;
+
+use crate::{
+    constant::root_config_dir,
+    util::{
+        http::{test_request::TestRequest, test_response::response_body_str},
+        test_setup::TestSetup,
+    },
+};
+
+#[tokio::test]
+async fn match_client_ip_exact_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/client-ip/exact", port).send().await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "client_ip exact matched");
+}
+
+#[tokio::test]
+async fn match_client_ip_cidr_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/client-ip/cidr", port).send().await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "client_ip cidr matched");
+}
+
+#[tokio::test]
+async fn not_match_client_ip_cidr_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/client-ip/private", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup =
+        TestSetup::default_with_root_config_dir(root_config_dir::RULE_WHEN_REQUEST_CLIENT_IP);
+    let port = test_setup.launch().await;
+    port
+}
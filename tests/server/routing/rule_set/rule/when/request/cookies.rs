@@ -0,0 +1,81 @@
+use hyper::{
+    header::{HeaderValue, COOKIE},
+    HeaderMap, StatusCode,
+};
+
+use crate::{
+    constant::root_config_dir,
+    util::{
+        http::{test_request::TestRequest, test_response::response_body_str},
+        test_setup::TestSetup,
+    },
+};
+
+#[tokio::test]
+async fn match_cookies_equal_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/cookies", port)
+        .with_headers(&cookie_headers("theme=dark; session=abc"))
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "cookies session.equal matched");
+}
+
+#[tokio::test]
+async fn not_match_cookies_equal_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/cookies", port)
+        .with_headers(&cookie_headers("session=abd"))
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn match_cookies_regex_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/cookies/tenant", port)
+        .with_headers(&cookie_headers("tenant=acme"))
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "tenant is acme");
+}
+
+#[tokio::test]
+async fn match_cookies_absent_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/cookies/absent", port).send().await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "cookies session.absent matched");
+}
+
+/// headers with cookie
+fn cookie_headers(cookie: &'static str) -> HeaderMap {
+    [(COOKIE, HeaderValue::from_static(cookie))]
+        .into_iter()
+        .collect()
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup =
+        TestSetup::default_with_root_config_dir(root_config_dir::RULE_WHEN_REQUEST_COOKIES);
+    let port = test_setup.launch().await;
+    port
+}
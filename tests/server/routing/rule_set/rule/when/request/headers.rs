@@ -177,6 +177,44 @@ async fn not_match_headers_key_1() {
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
 
+#[tokio::test]
+async fn match_headers_case_insensitive_1() {
+    let port = setup().await;
+
+    let headers: HeaderMap<HeaderValue> = [("accept", " Application/JSON ")]
+        .iter()
+        .map(|(k, v)| (HeaderName::from_static(k), HeaderValue::from_static(v)))
+        .collect();
+    let response = TestRequest::default("/headers/case-insensitive", port)
+        .with_headers(&headers)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(
+        body_str.as_str(),
+        "headers accept.equal case insensitive matched"
+    );
+}
+
+#[tokio::test]
+async fn not_match_headers_case_insensitive_1() {
+    let port = setup().await;
+
+    let headers: HeaderMap<HeaderValue> = [("accept", "application/xml")]
+        .iter()
+        .map(|(k, v)| (HeaderName::from_static(k), HeaderValue::from_static(v)))
+        .collect();
+    let response = TestRequest::default("/headers/case-insensitive", port)
+        .with_headers(&headers)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
 /// internal setup fn
 async fn setup() -> u16 {
     let test_setup =
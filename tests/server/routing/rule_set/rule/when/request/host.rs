@@ -0,0 +1,84 @@
+use hyper::{
+    header::{HeaderValue, HOST},
+    HeaderMap, StatusCode,
+};
+
+use crate::{
+    constant::root_config_dir,
+    util::{
+        http::{test_request::TestRequest, test_response::response_body_str},
+        test_setup::TestSetup,
+    },
+};
+
+#[tokio::test]
+async fn match_host_equal_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/host", port)
+        .with_headers(&host_headers("tenant-a.example.com:3001"))
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "host tenant-a matched");
+}
+
+#[tokio::test]
+async fn match_host_regex_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/host", port)
+        .with_headers(&host_headers("tenant-b.example.com"))
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "host of tenant-b");
+}
+
+#[tokio::test]
+async fn match_host_detailed_uppercase() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/host", port)
+        .with_headers(&host_headers("api.example.com"))
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "host api matched");
+}
+
+#[tokio::test]
+async fn not_match_host_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/host", port)
+        .with_headers(&host_headers("example.org"))
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+/// headers with host
+fn host_headers(host: &'static str) -> HeaderMap {
+    [(HOST, HeaderValue::from_static(host))]
+        .into_iter()
+        .collect()
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup =
+        TestSetup::default_with_root_config_dir(root_config_dir::RULE_WHEN_REQUEST_HOST);
+    let port = test_setup.launch().await;
+    port
+}
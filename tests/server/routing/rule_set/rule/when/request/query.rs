@@ -0,0 +1,101 @@
+use hyper::StatusCode;
+
+use crate::{
+    constant::root_config_dir,
+    util::{
+        http::{test_request::TestRequest, test_response::response_body_str},
+        test_setup::TestSetup,
+    },
+};
+
+#[tokio::test]
+async fn match_query_equal_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/query?page=2", port).send().await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "query page.equal matched");
+}
+
+#[tokio::test]
+async fn not_match_query_equal_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/query?page=3", port).send().await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn match_query_repeated_key_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/query?tag=a&tag=b%20c", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "query tag.equal matched");
+}
+
+#[tokio::test]
+async fn match_query_percent_decoded_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/query?tag=b+c", port).send().await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "query tag.equal matched");
+}
+
+#[tokio::test]
+async fn match_query_exists_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/query?debug", port).send().await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "query debug.exists matched");
+}
+
+#[tokio::test]
+async fn match_query_absent_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/query/absent?page=1", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "query token.absent matched");
+}
+
+#[tokio::test]
+async fn not_match_query_absent_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/query/absent?token=x", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+/// internal setup fn
+async fn setup() -> u16 {
+    let test_setup =
+        TestSetup::default_with_root_config_dir(root_config_dir::RULE_WHEN_REQUEST_QUERY);
+    let port = test_setup.launch().await;
+    port
+}
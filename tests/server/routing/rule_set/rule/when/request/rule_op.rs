@@ -360,6 +360,217 @@ async fn not_matches_wild_card_4() {
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
 
+#[tokio::test]
+async fn matches_regex_1() {
+    let port: u16 = setup().await;
+
+    let response = TestRequest::default("/rule-op/regex/users/1", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/json"
+    );
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(
+        body_str.as_str(),
+        json!({"op": "regex", "id": "1"}).to_string()
+    );
+}
+
+#[tokio::test]
+async fn matches_regex_2() {
+    let port: u16 = setup().await;
+
+    let headers: HeaderMap<HeaderValue> = [("user", "admin-1")]
+        .iter()
+        .map(|(k, v)| {
+            (
+                HeaderName::from_str(k).expect("failed to define header name"),
+                HeaderValue::from_static(v),
+            )
+        })
+        .collect();
+    let response = TestRequest::default("/rule-op/regex/text/alice", port)
+        .with_headers(&headers)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "alice as admin");
+}
+
+#[tokio::test]
+async fn matches_regex_file_capture() {
+    let port: u16 = setup().await;
+
+    let mut headers = HeaderMap::new();
+    headers.insert("file", HeaderValue::from_static("1.json"));
+    let response = TestRequest::default("/rule-op/regex/file", port)
+        .with_headers(&headers)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn matches_regex_file_capture_out_of_dir() {
+    let port: u16 = setup().await;
+
+    for file in ["..", "../../rule_op.toml", "/etc/hostname"] {
+        let mut headers = HeaderMap::new();
+        headers.insert("file", HeaderValue::from_static(file));
+        let response = TestRequest::default("/rule-op/regex/file", port)
+            .with_headers(&headers)
+            .send()
+            .await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND, "{}", file);
+    }
+}
+
+#[tokio::test]
+async fn not_matches_regex_1() {
+    let port: u16 = setup().await;
+
+    let response = TestRequest::default("/rule-op/regex/users/abc", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn not_matches_regex_2() {
+    let port: u16 = setup().await;
+
+    let response = TestRequest::default("/rule-op/regex/users/2", port)
+        .send()
+        .await;
+
+    // matches rule but response file is missing
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn matches_between_1() {
+    let port: u16 = setup().await;
+
+    let response = TestRequest::default("/rule-op/numeric/between?page=10", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "page in range");
+}
+
+#[tokio::test]
+async fn not_matches_between_1() {
+    let port: u16 = setup().await;
+
+    let response = TestRequest::default("/rule-op/numeric/between?page=11", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn matches_gt_1() {
+    let port: u16 = setup().await;
+
+    let body = json!({"amount": 100.5});
+    let response = TestRequest::default("/rule-op/numeric/gt", port)
+        .with_body_as_json(body.to_string().as_str())
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "amount over 100");
+}
+
+#[tokio::test]
+async fn not_matches_gt_1() {
+    let port: u16 = setup().await;
+
+    let body = json!({"amount": 99});
+    let response = TestRequest::default("/rule-op/numeric/gt", port)
+        .with_body_as_json(body.to_string().as_str())
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn matches_type_1() {
+    let port: u16 = setup().await;
+
+    let body = json!({"coupon": null});
+    let response = TestRequest::default("/rule-op/type", port)
+        .with_body_as_json(body.to_string().as_str())
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "coupon is null");
+}
+
+#[tokio::test]
+async fn not_matches_type_1() {
+    let port: u16 = setup().await;
+
+    let body = json!({"coupon": "null"});
+    let response = TestRequest::default("/rule-op/type", port)
+        .with_body_as_json(body.to_string().as_str())
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn matches_length_1() {
+    let port: u16 = setup().await;
+
+    let body = json!({"items": [1, 2, 3]});
+    let response = TestRequest::default("/rule-op/length", port)
+        .with_body_as_json(body.to_string().as_str())
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "three items");
+}
+
+#[tokio::test]
+async fn not_matches_length_1() {
+    let port: u16 = setup().await;
+
+    let body = json!({"items": [1, 2]});
+    let response = TestRequest::default("/rule-op/length", port)
+        .with_body_as_json(body.to_string().as_str())
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
 /// internal setup fn
 async fn setup() -> u16 {
     let test_setup =
@@ -1,4 +1,5 @@
 use hyper::StatusCode;
+use serde_json::json;
 
 use crate::{
     constant::root_config_dir,
@@ -86,6 +87,58 @@ async fn not_match_out_of_prefix_2() {
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
 
+#[tokio::test]
+async fn match_path_template_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/url-path/users/12/orders/a-3", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), "user 12 order a-3");
+}
+
+#[tokio::test]
+async fn match_path_template_2() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/url-path/users/1", port).send().await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/json"
+    );
+
+    let body_str = response_body_str(response).await;
+    assert_eq!(body_str.as_str(), json!({"user": "1"}).to_string());
+}
+
+#[tokio::test]
+async fn not_match_path_template_1() {
+    let port = setup().await;
+
+    let response = TestRequest::default("/url-path/users/12/orders", port)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn not_match_path_template_2() {
+    let port = setup().await;
+
+    // matches rule but response file is missing
+    let response = TestRequest::default("/url-path/users/2", port).send().await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
 /// internal setup fn
 async fn setup() -> u16 {
     let test_setup =
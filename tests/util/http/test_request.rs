@@ -60,6 +60,11 @@ impl TestRequest {
 
     /// send request to get http response from mock server
     pub async fn send(&self) -> Response<Incoming> {
+        self.try_send().await.unwrap()
+    }
+
+    /// send request, keeping error such as connection closed by mock server
+    pub async fn try_send(&self) -> Result<Response<Incoming>, hyper::Error> {
         let url: Uri = Uri::builder()
             .scheme("http")
             .authority(format!("127.0.0.1:{}", self.port.to_string()))
@@ -84,15 +89,24 @@ impl TestRequest {
 
         let authority = url.authority().unwrap().clone();
 
-        let path = url.path();
+        let path = match url.path_and_query() {
+            Some(x) => x.as_str(),
+            None => url.path(),
+        };
         let body = if self.body.is_none() {
             Empty::new().boxed()
         } else {
             Full::new(Bytes::from(self.body.as_ref().unwrap().to_owned())).boxed()
         };
-        let mut builder = Request::builder()
-            .uri(path)
-            .header(hyper::header::HOST, authority.as_str());
+        let mut builder = Request::builder().uri(path);
+        // host header given by test case is prior to default
+        let has_host = self
+            .headers
+            .as_ref()
+            .is_some_and(|x| x.contains_key(hyper::header::HOST));
+        if !has_host {
+            builder = builder.header(hyper::header::HOST, authority.as_str());
+        }
         if let Some(http_method) = self.http_method.as_ref() {
             builder = builder.method(http_method);
         }
@@ -103,6 +117,6 @@ impl TestRequest {
         }
         let req = builder.body(body).expect("failed to create http request");
 
-        sender.send_request(req).await.unwrap()
+        sender.send_request(req).await
     }
 }